@@ -0,0 +1,112 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Part, Result};
+
+/// Answers recorded in a day's `answers.toml`, next to its `input`:
+///
+/// ```toml
+/// part1 = "24000"
+/// part2 = "45000"
+/// ```
+///
+/// Parts that haven't been solved yet are simply left out.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Outcome of comparing an answer with the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    /// Path of the answer file belonging to the `input` file at `input`.
+    pub fn path_for(input: impl AsRef<Path>) -> PathBuf {
+        input.as_ref().with_file_name("answers.toml")
+    }
+
+    /// Reads the answer file at `path`, which doesn't have to exist.
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers> {
+        let path = path.as_ref();
+
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents).map_err(|message| Error::Answers {
+                path: path.to_path_buf(),
+                message,
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(source) => Err(Error::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    fn parse(contents: &str) -> std::result::Result<Answers, String> {
+        let table: toml::Table = contents
+            .parse()
+            .map_err(|err: toml::de::Error| err.message().to_string())?;
+
+        let mut answers = Answers::default();
+
+        for (key, value) in table {
+            let toml::Value::String(answer) = value else {
+                return Err(format!("`{key}` must be a string"));
+            };
+
+            match key.as_str() {
+                "part1" => answers.part1 = Some(answer),
+                "part2" => answers.part2 = Some(answer),
+                _ => return Err(format!("unknown key `{key}`, expected `part1` or `part2`")),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    /// Compares `answer` with the recorded one, ignoring trailing whitespace
+    /// so rendered images can be written down without it.
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if normalize(expected) == normalize(answer) => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+fn normalize(answer: &str) -> String {
+    answer
+        .trim_end()
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Missing => write!(f, "MISSING"),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::{fmt, path::PathBuf, process::ExitCode};
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Stdin(std::io::Error),
+    /// An output file, like an exported image, could not be written.
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Usage(String),
+    Argument(String),
+    /// The puzzle input is malformed.
+    Parse(Box<dyn std::error::Error>),
+    /// A day's `answers.toml` is malformed.
+    Answers {
+        path: PathBuf,
+        message: String,
+    },
+    /// This many answers differ from the recorded ones.
+    Mismatch(usize),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "could not read {}: {source}", path.display()),
+            Error::Stdin(source) => write!(f, "could not read stdin: {source}"),
+            Error::Write { path, source } => {
+                write!(f, "could not write {}: {source}", path.display())
+            }
+            Error::Usage(usage) => write!(f, "usage: {usage}"),
+            Error::Argument(message) => write!(f, "{message}"),
+            Error::Parse(source) => write!(f, "invalid input: {source}"),
+            Error::Answers { path, message } => write!(f, "invalid {}: {message}", path.display()),
+            Error::Mismatch(1) => write!(f, "1 answer differs from the recorded one"),
+            Error::Mismatch(count) => write!(f, "{count} answers differ from the recorded ones"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } | Error::Stdin(source) | Error::Write { source, .. } => {
+                Some(source)
+            }
+            Error::Parse(source) => Some(source.as_ref()),
+            Error::Usage(_) | Error::Argument(_) | Error::Answers { .. } | Error::Mismatch(_) => {
+                None
+            }
+        }
+    }
+}
+
+/// Runs the body of a day's `main`, printing any error to stderr instead of
+/// panicking with a backtrace.
+pub fn run(body: impl FnOnce() -> Result<()>) -> ExitCode {
+    match body() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
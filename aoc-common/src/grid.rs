@@ -0,0 +1,275 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{numbered_lines, ParseError};
+
+/// Column and row of a cell, counted from the top left.
+pub type Pos = (usize, usize);
+
+/// Difference between two positions, as columns and rows.
+pub type Offset = (isize, isize);
+
+/// Offsets of the cells sharing a side with a cell: up, right, down and left.
+pub const NEIGHBOURS4: [Offset; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the cells sharing a side or a corner with a cell, clockwise
+/// from the top left.
+pub const NEIGHBOURS8: [Offset; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangle of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Grid with the given rows, or the index of the first row that isn't as
+    /// wide as the first one.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, usize> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            return Err(row);
+        }
+
+        let height = rows.len();
+        let cells = rows.into_iter().flatten().collect();
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Parses one cell per character, one row per line. `cell` returns `None`
+    /// for characters that aren't valid cells, which is reported as
+    /// `invalid`. Rows that aren't as wide as the first one are reported as
+    /// `uneven`.
+    pub fn parse<K: Copy>(
+        input: &str,
+        invalid: K,
+        uneven: K,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError<K>> {
+        let mut grid = Grid {
+            cells: vec![],
+            width: 0,
+            height: 0,
+        };
+
+        for (number, line) in numbered_lines(input) {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(i, c)| cell(c).ok_or_else(|| ParseError::new(invalid, number, i + 1, c)))
+                .collect::<Result<Vec<T>, _>>()?;
+
+            if grid.height == 0 {
+                grid.width = row.len();
+            } else if row.len() != grid.width {
+                return Err(ParseError::line(uneven, number, line));
+            }
+
+            grid.push_row(row);
+        }
+
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Adds a row at the bottom.
+    ///
+    /// # Panics
+    ///
+    /// If the row isn't as wide as the grid.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let len = self.cells.len();
+        self.cells.extend(row);
+        assert_eq!(
+            self.cells.len() - len,
+            self.width,
+            "row is not as wide as the grid"
+        );
+        self.height += 1;
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// `pos` moved by `offset`, if that is still inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): Offset) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.contains(pos).then_some(pos)
+    }
+
+    /// `pos` moved by `offset`, coming back in on the opposite side when
+    /// leaving the grid.
+    pub fn wrapping_offset(&self, (x, y): Pos, (dx, dy): Offset) -> Pos {
+        let wrap = |coord: usize, delta: isize, len: usize| {
+            let moved = coord as isize + delta;
+
+            // Only divide when actually leaving the grid; this is a hot path.
+            if (0..len as isize).contains(&moved) {
+                moved as usize
+            } else {
+                moved.rem_euclid(len as isize) as usize
+            }
+        };
+
+        (wrap(x, dx, self.width), wrap(y, dy, self.height))
+    }
+
+    /// Positions sharing a side with `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// Positions sharing a side or a corner with `pos` that are inside the
+    /// grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} is outside the grid");
+
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // `chunks` panics on a chunk size of zero.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Grid with `margin` cells of `fill` added on every side.
+    pub fn expand(&self, margin: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width + 2 * margin;
+        let mut grid = Grid::new(width, 0, fill.clone());
+
+        let empty = vec![fill.clone(); width];
+        for _ in 0..margin {
+            grid.push_row(empty.iter().cloned());
+        }
+        for row in self.rows() {
+            let side = std::iter::repeat_n(&fill, margin);
+            grid.push_row(side.clone().chain(row).chain(side).cloned());
+        }
+        for _ in 0..margin {
+            grid.push_row(empty.iter().cloned());
+        }
+
+        grid
+    }
+
+    /// Draws the grid one line per row, with `cell` choosing the character
+    /// of each cell.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut image = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows().take(self.height) {
+            image.extend(row.iter().map(&mut cell));
+            image.push('\n');
+        }
+
+        image
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(x < self.width, "{:?} is outside the grid", (x, y));
+
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(x < self.width, "{:?} is outside the grid", (x, y));
+
+        &mut self.cells[y * self.width + x]
+    }
+}
@@ -0,0 +1,70 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::{log, Error, Result};
+
+/// Where the puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Source {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Picks the source from the command line: an optional single argument
+    /// that is either a path or `-`, falling back to `default`. A `-v` or
+    /// `-vv` flag sets the log verbosity.
+    pub fn from_args(default: impl Into<PathBuf>) -> Result<Source> {
+        let mut args = std::env::args();
+        let program = args.next().unwrap_or_default();
+        let mut args: Vec<String> = args.collect();
+
+        args.retain(|arg| match log::verbosity_from_arg(arg) {
+            Some(verbosity) => {
+                log::set_verbosity(verbosity);
+                false
+            }
+            None => true,
+        });
+
+        match args.as_slice() {
+            [] => Ok(Source::File(default.into())),
+            [arg] if !arg.starts_with("--") => Ok(Source::from_arg(arg)),
+            _ => Err(Error::Usage(format!("{program} [-v | -vv] [INPUT | -]"))),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(Error::Stdin)?;
+
+                Ok(input)
+            }
+            Source::File(path) => read_input(path),
+        }
+    }
+}
+
+/// Reads the whole puzzle input at `path`.
+pub fn read_input(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+
+    std::fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
@@ -0,0 +1,33 @@
+//! Code shared by every day: loading the puzzle input, splitting it into lines
+//! or blank-line separated groups, a [`Grid`] of cells, graph [`Search`],
+//! reporting malformed input and other errors from `main`, logging to stderr,
+//! reading the letters in rendered images, the [`Solution`] trait every day
+//! implements, and the recorded [`Answers`] to check solutions against.
+
+mod answers;
+mod error;
+mod grid;
+mod input;
+pub mod log;
+pub mod ocr;
+mod parse_error;
+mod search;
+mod solution;
+
+pub use answers::{Answers, Verdict};
+pub use error::{run, Error, Result};
+pub use grid::{Grid, Offset, Pos, NEIGHBOURS4, NEIGHBOURS8};
+pub use input::{read_input, Source};
+pub use parse_error::{numbered_groups, numbered_lines, parse_at, ParseError};
+pub use search::{Found, Search, Visited, VisitedBy};
+pub use solution::{
+    answer, main, print_answer, solve, Day, Event, NoAnswer, Part, Report, Solution,
+};
+
+/// Path of the `input` file next to the calling crate's `Cargo.toml`.
+#[macro_export]
+macro_rules! input_path {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/input")
+    };
+}
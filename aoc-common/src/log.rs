@@ -0,0 +1,60 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// How much a solver tells about its work. Messages go to stderr, so they
+/// never mix with the answers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Progress, like which blueprint is being searched. Shown with `-v`.
+    Info = 1,
+    /// Internals, like search node counts or detected periods. Shown with
+    /// `-vv`.
+    Debug = 2,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// Shows messages up to `verbosity`, the number of `v`s given on the command
+/// line. Nothing is shown by default.
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+/// Number of `v`s in a `-v` or `-vv` flag.
+pub fn verbosity_from_arg(arg: &str) -> Option<u8> {
+    match arg {
+        "-v" => Some(1),
+        "-vv" => Some(2),
+        _ => None,
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// Used by [`info!`](crate::info) and [`debug!`](crate::debug).
+#[doc(hidden)]
+pub fn log(level: Level, target: &str, message: fmt::Arguments) {
+    if enabled(level) {
+        eprintln!("{target}: {message}");
+    }
+}
+
+/// Logs progress to stderr, shown with `-v`.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Info, module_path!(), format_args!($($arg)+))
+    };
+}
+
+/// Logs internals to stderr, shown with `-vv`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Debug, module_path!(), format_args!($($arg)+))
+    };
+}
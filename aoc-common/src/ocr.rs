@@ -0,0 +1,66 @@
+//! Reading the block letters some puzzles draw, like the CRT of day 10.
+
+/// Letters are four pixels wide with a blank column after each one.
+const LETTER_WIDTH: usize = 5;
+const LETTER_HEIGHT: usize = 6;
+
+/// The letters that show up in answers, as drawn by the puzzles.
+const FONT: [(char, [&str; LETTER_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The letters in `image`, or the image itself if it doesn't read as letters.
+pub fn read(image: &str) -> String {
+    recognise(image).unwrap_or_else(|| image.to_string())
+}
+
+/// Reads the letters in `image`, six lines with `#` for lit pixels and
+/// anything else for dark ones. Returns `None` if the image isn't six lines
+/// high or has something other than known letters in it.
+pub fn recognise(image: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = image
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+
+    if rows.len() != LETTER_HEIGHT {
+        return None;
+    }
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+
+    let letters: String = (0..width)
+        .step_by(LETTER_WIDTH)
+        .map(|left| {
+            FONT.iter()
+                .find(|(_, glyph)| {
+                    glyph.iter().enumerate().all(|(y, row)| {
+                        (0..LETTER_WIDTH).all(|dx| {
+                            let pixel = row.as_bytes().get(dx) == Some(&b'#');
+                            lit(left + dx, y) == pixel
+                        })
+                    })
+                })
+                .map(|&(letter, _)| letter)
+        })
+        .collect::<Option<_>>()?;
+
+    (!letters.is_empty()).then_some(letters)
+}
@@ -0,0 +1,92 @@
+use std::{fmt, str::FromStr};
+
+/// A problem with the puzzle input, pointing at the offending text. Every day
+/// has its own `ErrorKind` enum describing what went wrong.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError<K> {
+    pub kind: K,
+    /// Line number, counted from 1.
+    pub line: usize,
+    /// Column of the first offending character, counted from 1.
+    pub column: usize,
+    pub text: String,
+}
+
+impl<K> ParseError<K> {
+    pub fn new(kind: K, line: usize, column: usize, text: impl Into<String>) -> Self {
+        ParseError {
+            kind,
+            line,
+            column,
+            text: text.into(),
+        }
+    }
+
+    /// Error about `text`, which must be a slice of `line`, the input line
+    /// with number `number`.
+    pub fn at(kind: K, number: usize, line: &str, text: &str) -> Self {
+        // `text` borrows from `line`, so its offset gives the column.
+        let offset = (text.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = match line.get(..offset) {
+            Some(before) => before.chars().count() + 1,
+            None => 1,
+        };
+
+        ParseError::new(kind, number, column, text)
+    }
+
+    /// Error about all of line `number`.
+    pub fn line(kind: K, number: usize, line: &str) -> Self {
+        ParseError::at(kind, number, line, line)
+    }
+
+    /// Error about something missing from the end of `input`.
+    pub fn end(kind: K, input: &str) -> Self {
+        ParseError::new(kind, input.lines().count() + 1, 1, "")
+    }
+}
+
+impl<K: fmt::Display> fmt::Display for ParseError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )?;
+
+        if !self.text.is_empty() {
+            write!(f, " (found `{}`)", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<K: fmt::Debug + fmt::Display> std::error::Error for ParseError<K> {}
+
+/// Lines of `input` with their line numbers, counted from 1.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// Like [`crate::groups`], but keeping the line numbers.
+pub fn numbered_groups(input: &str) -> Vec<Vec<(usize, &str)>> {
+    let lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+
+    lines
+        .split(|(_, line)| line.is_empty())
+        .filter(|group| !group.is_empty())
+        .map(<[(usize, &str)]>::to_vec)
+        .collect()
+}
+
+/// Parses `text`, a slice of line `number`, reporting `kind` if that fails.
+pub fn parse_at<T: FromStr, K>(
+    text: &str,
+    kind: K,
+    number: usize,
+    line: &str,
+) -> Result<T, ParseError<K>> {
+    text.parse()
+        .map_err(|_| ParseError::at(kind, number, line, text))
+}
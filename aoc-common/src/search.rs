@@ -0,0 +1,242 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::{Grid, Pos};
+
+/// How a search remembers the nodes it already reached.
+pub trait Visited<N> {
+    /// Marks `node` as reached, returning whether it wasn't before.
+    fn visit(&mut self, node: &N) -> bool;
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> for HashSet<N> {
+    fn visit(&mut self, node: &N) -> bool {
+        self.insert(node.clone())
+    }
+}
+
+/// One flag per cell, for searches over a grid.
+impl Visited<Pos> for Grid<bool> {
+    fn visit(&mut self, &pos: &Pos) -> bool {
+        !std::mem::replace(&mut self[pos], true)
+    }
+}
+
+/// Remembers nodes by a key, for nodes carrying state that doesn't matter
+/// when telling whether they were reached before.
+pub struct VisitedBy<K, F> {
+    seen: HashSet<K>,
+    key: F,
+}
+
+impl<K, F> VisitedBy<K, F> {
+    pub fn new(key: F) -> Self {
+        VisitedBy {
+            seen: HashSet::new(),
+            key,
+        }
+    }
+}
+
+impl<N, K: Eq + Hash, F: FnMut(&N) -> K> Visited<N> for VisitedBy<K, F> {
+    fn visit(&mut self, node: &N) -> bool {
+        self.seen.insert((self.key)(node))
+    }
+}
+
+/// A search from one or more starts. Picks how to remember visited nodes and
+/// whether to keep the way back before running as breadth-first search,
+/// Dijkstra or A*.
+pub struct Search<N, V = HashSet<N>> {
+    starts: Vec<N>,
+    visited: V,
+    parents: Option<HashMap<N, N>>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    pub fn new(start: N) -> Self {
+        Search::from_starts([start])
+    }
+
+    /// Search from all of `starts` at once, finding whichever reaches the
+    /// goal first.
+    pub fn from_starts(starts: impl IntoIterator<Item = N>) -> Self {
+        Search::with_visited(starts, HashSet::new())
+    }
+}
+
+impl<N: Clone + Eq + Hash, V: Visited<N>> Search<N, V> {
+    pub fn with_visited(starts: impl IntoIterator<Item = N>, visited: V) -> Self {
+        Search {
+            starts: starts.into_iter().collect(),
+            visited,
+            parents: None,
+        }
+    }
+
+    /// Remembers where each node was reached from, so that [`Found::path`]
+    /// works.
+    pub fn track_parents(mut self) -> Self {
+        self.parents = Some(HashMap::new());
+        self
+    }
+
+    fn reached(&mut self, node: &N, parent: &N) {
+        if let Some(parents) = &mut self.parents {
+            parents
+                .entry(node.clone())
+                .or_insert_with(|| parent.clone());
+        }
+    }
+
+    /// Breadth-first search, with every step costing one.
+    pub fn bfs<I: IntoIterator<Item = N>>(
+        mut self,
+        mut successors: impl FnMut(&N) -> I,
+        mut success: impl FnMut(&N) -> bool,
+    ) -> Option<Found<N, usize>> {
+        let mut queue = VecDeque::new();
+        for start in std::mem::take(&mut self.starts) {
+            if self.visited.visit(&start) {
+                queue.push_back((start, 0));
+            }
+        }
+
+        while let Some((node, cost)) = queue.pop_front() {
+            if success(&node) {
+                return Some(Found {
+                    node,
+                    cost,
+                    parents: self.parents,
+                });
+            }
+
+            for next in successors(&node) {
+                if self.visited.visit(&next) {
+                    self.reached(&next, &node);
+                    queue.push_back((next, cost + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Visits everything reachable from the starts, breadth first.
+    pub fn flood<I: IntoIterator<Item = N>>(mut self, mut successors: impl FnMut(&N) -> I) -> V {
+        let mut queue = VecDeque::new();
+        for start in std::mem::take(&mut self.starts) {
+            if self.visited.visit(&start) {
+                queue.push_back(start);
+            }
+        }
+
+        while let Some(node) = queue.pop_front() {
+            for next in successors(&node) {
+                if self.visited.visit(&next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        self.visited
+    }
+
+    /// Cheapest path, with `successors` giving the cost of each step.
+    pub fn dijkstra<C, I>(
+        self,
+        successors: impl FnMut(&N) -> I,
+        success: impl FnMut(&N) -> bool,
+    ) -> Option<Found<N, C>>
+    where
+        C: Copy + Ord + Add<Output = C> + Default,
+        I: IntoIterator<Item = (N, C)>,
+    {
+        self.astar(successors, |_| C::default(), success)
+    }
+
+    /// Cheapest path, exploring the nodes that look closest to the goal
+    /// first. `heuristic` must never overestimate the remaining cost.
+    pub fn astar<C, I>(
+        mut self,
+        mut successors: impl FnMut(&N) -> I,
+        mut heuristic: impl FnMut(&N) -> C,
+        mut success: impl FnMut(&N) -> bool,
+    ) -> Option<Found<N, C>>
+    where
+        C: Copy + Ord + Add<Output = C> + Default,
+        I: IntoIterator<Item = (N, C)>,
+    {
+        // Queued nodes live in `nodes`, with the index of the node they were
+        // reached from, so that the heap doesn't need them to be `Ord`. Ties
+        // go to the node queued last.
+        let mut nodes: Vec<(N, C, Option<usize>)> = vec![];
+        let mut heap = BinaryHeap::new();
+
+        for start in std::mem::take(&mut self.starts) {
+            heap.push((Reverse(heuristic(&start)), nodes.len()));
+            nodes.push((start, C::default(), None));
+        }
+
+        while let Some((_, index)) = heap.pop() {
+            let (node, cost, parent) = &nodes[index];
+            let (node, cost) = (node.clone(), *cost);
+            if !self.visited.visit(&node) {
+                continue;
+            }
+            if let Some(parent) = *parent {
+                self.reached(&node, &nodes[parent].0);
+            }
+
+            if success(&node) {
+                return Some(Found {
+                    node,
+                    cost,
+                    parents: self.parents,
+                });
+            }
+
+            for (next, step) in successors(&node) {
+                let cost = cost + step;
+                heap.push((Reverse(cost + heuristic(&next)), nodes.len()));
+                nodes.push((next, cost, Some(index)));
+            }
+        }
+
+        None
+    }
+}
+
+/// The node a search was looking for, and what it cost to get there.
+#[derive(Clone, Debug)]
+pub struct Found<N, C> {
+    pub node: N,
+    pub cost: C,
+    parents: Option<HashMap<N, N>>,
+}
+
+impl<N: Clone + Eq + Hash, C> Found<N, C> {
+    /// Nodes from the start to the found node, both included.
+    ///
+    /// # Panics
+    ///
+    /// If the search didn't [track parents](Search::track_parents).
+    pub fn path(&self) -> Vec<N> {
+        let parents = self
+            .parents
+            .as_ref()
+            .expect("the search needs to track parents to find the path");
+
+        let mut path = vec![self.node.clone()];
+        while let Some(parent) = parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        path
+    }
+}
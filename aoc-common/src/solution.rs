@@ -0,0 +1,176 @@
+use std::{
+    fmt,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use crate::{run, Error, Result, Source};
+
+/// A day's puzzle: how to parse its input and how to answer both parts.
+pub trait Solution {
+    const DAY: u8;
+    /// The day's own `input` file, used when no other input is given.
+    const INPUT: &'static str;
+
+    type Input;
+    type Error: std::error::Error + 'static;
+
+    fn parse(input: &str) -> std::result::Result<Self::Input, Self::Error>;
+    /// The answer, or what about the input keeps this part from having one.
+    /// Problems only one part runs into are found here rather than in
+    /// [`parse`](Solution::parse), so the other part can still be answered.
+    fn part1(input: &Self::Input) -> std::result::Result<impl fmt::Display, Self::Error>;
+    fn part2(input: &Self::Input) -> std::result::Result<impl fmt::Display, Self::Error>;
+
+    /// The image a part's answer is read from, for days that draw their
+    /// answers. Shown instead of the answer when asked for.
+    fn image(_input: &Self::Input, _part: Part) -> Option<String> {
+        None
+    }
+}
+
+/// Answer of a part that doesn't exist, like the second part of day 25.
+pub struct NoAnswer;
+
+impl fmt::Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "-")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_arg(arg: &str) -> Option<Part> {
+        match arg {
+            "1" => Some(Part::One),
+            "2" => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part1"),
+            Part::Two => write!(f, "part2"),
+        }
+    }
+}
+
+/// Progress of [`solve`], with the wall time each step took.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    Parsed {
+        elapsed: Duration,
+    },
+    Answered {
+        part: Part,
+        answer: String,
+        elapsed: Duration,
+    },
+}
+
+/// Receives each [`Event`] as soon as it happens.
+pub type Report<'a> = &'a mut dyn FnMut(Event);
+
+/// Parses `input` once and answers the requested parts, with the [image they
+/// are read from](Solution::image) instead if there is one and `images` is
+/// set.
+pub fn solve<S: Solution>(input: &str, parts: &[Part], images: bool, report: Report) -> Result<()> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|err| Error::Parse(Box::new(err)))?;
+    report(Event::Parsed {
+        elapsed: start.elapsed(),
+    });
+
+    for part in parts {
+        let start = Instant::now();
+        let image = images.then(|| S::image(&parsed, *part)).flatten();
+        let answer = match (part, image) {
+            (_, Some(image)) => image,
+            (Part::One, None) => S::part1(&parsed)
+                .map_err(|err| Error::Parse(Box::new(err)))?
+                .to_string(),
+            (Part::Two, None) => S::part2(&parsed)
+                .map_err(|err| Error::Parse(Box::new(err)))?
+                .to_string(),
+        };
+
+        report(Event::Answered {
+            part: *part,
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Answer to a single part, panicking if `input` is malformed. Meant for
+/// tests.
+pub fn answer<S: Solution>(input: &str, part: Part) -> String {
+    let mut answer = String::new();
+
+    solve::<S>(input, &[part], false, &mut |event| {
+        if let Event::Answered { answer: solved, .. } = event {
+            answer = solved;
+        }
+    })
+    .unwrap_or_else(|err| panic!("{err}"));
+
+    answer
+}
+
+/// Prints an answer, putting multi-line answers (like rendered images) below
+/// the part name.
+pub fn print_answer(part: Part, answer: &str) {
+    if answer.contains('\n') {
+        println!("{part}:\n{answer}");
+    } else {
+        println!("{part}: {answer}");
+    }
+}
+
+/// The whole `main` of a day's binary.
+pub fn main<S: Solution>() -> ExitCode {
+    run(|| {
+        let input = Source::from_args(S::INPUT)?.read()?;
+
+        solve::<S>(&input, &Part::ALL, false, &mut |event| {
+            if let Event::Answered { part, answer, .. } = event {
+                print_answer(part, &answer);
+            }
+        })
+    })
+}
+
+/// Type-erased handle to a day's [`Solution`], so the runner can keep all days
+/// in a single list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    solve: fn(&str, &[Part], bool, Report) -> Result<()>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day {
+            number: S::DAY,
+            input: S::INPUT,
+            solve: solve::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str, parts: &[Part], images: bool, report: Report) -> Result<()> {
+        (self.solve)(input, parts, images, report)
+    }
+}
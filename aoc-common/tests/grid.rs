@@ -0,0 +1,80 @@
+use aoc_common::{Grid, ParseError};
+
+const MAP: &str = "\
+#..
+.#.
+..#
+#.#";
+
+fn parse(input: &str) -> Result<Grid<bool>, ParseError<&'static str>> {
+    Grid::parse(input, "invalid", "uneven", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+#[test]
+fn parse_and_render() {
+    let grid = parse(MAP).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 4));
+    assert!(grid[(1, 1)]);
+    assert_eq!(
+        grid.render(|&wall| if wall { '#' } else { '.' }),
+        format!("{MAP}\n")
+    );
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse("#.\n.x"), Err(ParseError::new("invalid", 2, 2, "x")));
+    assert_eq!(
+        parse("#.\n..."),
+        Err(ParseError::new("uneven", 2, 1, "..."))
+    );
+}
+
+#[test]
+fn neighbours_stay_inside() {
+    let grid = parse(MAP).unwrap();
+
+    assert_eq!(
+        grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(grid.offset((2, 3), (1, 0)), None);
+}
+
+#[test]
+fn wrapping() {
+    let grid = parse(MAP).unwrap();
+
+    assert_eq!(grid.wrapping_offset((0, 0), (-1, -1)), (2, 3));
+    assert_eq!(grid.wrapping_offset((2, 3), (1, 1)), (0, 0));
+    assert_eq!(grid.wrapping_offset((1, 1), (4, 0)), (2, 1));
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = parse(MAP).unwrap();
+
+    assert_eq!(grid.row(3), [true, false, true]);
+    assert_eq!(
+        grid.column(2).copied().collect::<Vec<_>>(),
+        [false, false, true, true]
+    );
+    assert_eq!(grid.rows().count(), 4);
+}
+
+#[test]
+fn expand() {
+    let grid = parse("#").unwrap().expand(1, false);
+
+    assert_eq!(
+        grid.render(|&wall| if wall { '#' } else { '.' }),
+        "...\n.#.\n...\n"
+    );
+}
@@ -0,0 +1,35 @@
+use aoc_common::ocr::recognise;
+
+#[test]
+fn letters() {
+    let image = "\
+.##..###..####.
+#..#.#..#.#....
+#..#.###..###..
+####.#..#.#....
+#..#.#..#.#....
+#..#.###..####.
+";
+
+    assert_eq!(recognise(image).as_deref(), Some("ABE"));
+}
+
+#[test]
+fn spaces_and_missing_trailing_columns() {
+    let image = "\
+#  # #
+#  # #
+#### #
+#  # #
+#  # #
+#  # ####";
+
+    assert_eq!(recognise(image).as_deref(), Some("HL"));
+}
+
+#[test]
+fn not_letters() {
+    assert_eq!(recognise("####\n####\n"), None);
+    assert_eq!(recognise(&"#.#.#.#.#.\n".repeat(6)), None);
+    assert_eq!(recognise(""), None);
+}
@@ -0,0 +1,87 @@
+use aoc_common::{Grid, Pos, Search, VisitedBy};
+
+const MAZE: &str = "\
+..#...
+.##.#.
+....#.
+.####.
+......";
+
+fn maze() -> Grid<bool> {
+    Grid::parse(MAZE, (), (), |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .unwrap()
+}
+
+fn open<'a>(maze: &'a Grid<bool>) -> impl FnMut(&Pos) -> Vec<Pos> + 'a {
+    |&pos| maze.neighbours4(pos).filter(|&next| !maze[next]).collect()
+}
+
+#[test]
+fn bfs_finds_the_shortest_path() {
+    let maze = maze();
+
+    let found = Search::new((0, 0))
+        .track_parents()
+        .bfs(open(&maze), |&pos| pos == (5, 0))
+        .unwrap();
+
+    assert_eq!(found.cost, 9);
+    assert_eq!(found.path().len(), 10);
+    assert_eq!(found.path().first(), Some(&(0, 0)));
+    assert_eq!(found.path().last(), Some(&(5, 0)));
+}
+
+#[test]
+fn multiple_starts() {
+    let maze = maze();
+
+    let found = Search::from_starts([(0, 0), (3, 0)])
+        .bfs(open(&maze), |&pos| pos == (5, 0))
+        .unwrap();
+
+    assert_eq!(found.cost, 2);
+}
+
+#[test]
+fn grid_visited_and_flood() {
+    let maze = maze();
+    let visited = Grid::new(maze.width(), maze.height(), false);
+
+    let reached = Search::with_visited([(0, 0)], visited).flood(open(&maze));
+
+    assert_eq!(reached.iter().filter(|(_, &seen)| seen).count(), 21);
+}
+
+#[test]
+fn dijkstra_and_astar_agree() {
+    let maze = maze();
+    // Going down costs more than going up.
+    let weighted = |&(x, y): &Pos| -> Vec<(Pos, usize)> {
+        maze.neighbours4((x, y))
+            .filter(|&next| !maze[next])
+            .map(|next| (next, if next.1 > y { 3 } else { 1 }))
+            .collect()
+    };
+    let distance = |&(x, y): &Pos| 5 - x + y;
+
+    let dijkstra = Search::new((0, 0)).dijkstra(weighted, |&pos| pos == (5, 0));
+    let astar = Search::new((0, 0)).astar(weighted, distance, |&pos| pos == (5, 0));
+
+    assert_eq!(dijkstra.unwrap().cost, 13);
+    assert_eq!(astar.unwrap().cost, 13);
+}
+
+#[test]
+fn visited_by_key() {
+    // Counting up forever, but only the last digit tells states apart, so the
+    // search runs out of new states.
+    let visited = VisitedBy::new(|n: &u32| n % 10);
+
+    let found = Search::with_visited([0], visited).bfs(|&n| [n + 1], |_| false);
+
+    assert!(found.is_none());
+}
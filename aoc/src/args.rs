@@ -0,0 +1,152 @@
+use aoc_common::{Error, Part, Result, Source};
+
+pub const USAGE: &str = "\
+aoc run <DAY | all> [--part <1 | 2>] [--input <PATH | ->] [--check] [--time] [--format <text | json>] [--image] [-v | -vv]
+       aoc bench <DAY | all> [--part <1 | 2>] [--input <PATH | ->] [--runs <N>] [-v | -vv]";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    /// Solve repeatedly and print statistics about how long each step took.
+    Bench,
+}
+
+/// How `run` prints the answers.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON object per answer and line, for scripts.
+    Json,
+}
+
+pub enum Days {
+    One(u8),
+    All,
+}
+
+pub struct Args {
+    pub command: Command,
+    pub days: Days,
+    pub parts: Vec<Part>,
+    /// Input to use instead of the day's own `input` file.
+    pub input: Option<Source>,
+    /// Compare the answers with the day's `answers.toml`.
+    pub check: bool,
+    /// Print how long parsing and each part took.
+    pub time: bool,
+    pub format: Format,
+    /// Print images, like day 10's CRT, instead of the letters read from
+    /// them.
+    pub image: bool,
+    /// How often `bench` solves each day.
+    pub runs: usize,
+    /// How much solvers log to stderr, from `-v` or `-vv`.
+    pub verbosity: u8,
+}
+
+impl Args {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Args> {
+        let usage = || Error::Usage(USAGE.to_string());
+
+        let command = match args.next().as_deref() {
+            Some("run") => Command::Run,
+            Some("bench") => Command::Bench,
+            _ => return Err(usage()),
+        };
+
+        let days = match args.next().as_deref() {
+            Some("all") => Days::All,
+            Some(day) => match day.parse() {
+                Ok(day @ 1..=25) => Days::One(day),
+                _ => {
+                    return Err(Error::Argument(format!(
+                        "unknown day `{day}`, expected 1-25 or `all`"
+                    )))
+                }
+            },
+            None => return Err(usage()),
+        };
+
+        let mut parts = Part::ALL.to_vec();
+        let mut input = None;
+        let mut check = false;
+        let mut time = false;
+        let mut format = Format::Text;
+        let mut image = false;
+        let mut runs = 10;
+        let mut verbosity = 0;
+
+        while let Some(flag) = args.next() {
+            match (command, flag.as_str()) {
+                (Command::Run, "--check") => check = true,
+                (Command::Run, "--time") => time = true,
+                (Command::Run, "--image") => image = true,
+                (Command::Run, "--format") => {
+                    let value = value(&mut args, &flag)?;
+                    format = match value.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        _ => {
+                            return Err(Error::Argument(format!(
+                                "unknown format `{value}`, expected `text` or `json`"
+                            )))
+                        }
+                    };
+                }
+                (_, "--part") => {
+                    let value = value(&mut args, &flag)?;
+                    let part = Part::from_arg(&value).ok_or_else(|| {
+                        Error::Argument(format!("unknown part `{value}`, expected 1 or 2"))
+                    })?;
+                    parts = vec![part];
+                }
+                (_, "--input") => input = Some(Source::from_arg(&value(&mut args, &flag)?)),
+                (Command::Bench, "--runs") => {
+                    let value = value(&mut args, &flag)?;
+                    runs = match value.parse() {
+                        Ok(runs @ 1..) => runs,
+                        _ => {
+                            return Err(Error::Argument(format!(
+                                "invalid number of runs `{value}`"
+                            )))
+                        }
+                    };
+                }
+                (_, flag) => match aoc_common::log::verbosity_from_arg(flag) {
+                    Some(v) => verbosity = v,
+                    None => return Err(usage()),
+                },
+            }
+        }
+
+        if matches!(days, Days::All) && input.is_some() {
+            return Err(Error::Argument(String::from(
+                "`--input` can only be used when running a single day",
+            )));
+        }
+
+        if check && input.is_some() {
+            return Err(Error::Argument(String::from(
+                "`--check` only works with the day's own input",
+            )));
+        }
+
+        Ok(Args {
+            command,
+            days,
+            parts,
+            input,
+            check,
+            time,
+            format,
+            image,
+            runs,
+            verbosity,
+        })
+    }
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String> {
+    args.next()
+        .ok_or_else(|| Error::Argument(format!("missing value for `{flag}`")))
+}
@@ -0,0 +1,175 @@
+use std::{process::ExitCode, time::Duration};
+
+use aoc_common::{Answers, Day, Event, Part, Result, Verdict};
+use args::{Args, Command, Days, Format};
+use timing::Stats;
+
+mod args;
+mod days;
+mod timing;
+
+fn main() -> ExitCode {
+    aoc_common::run(|| {
+        let args = Args::parse(std::env::args().skip(1))?;
+        aoc_common::log::set_verbosity(args.verbosity);
+
+        let days = match args.days {
+            Days::One(number) => vec![days::get(number).ok_or_else(|| {
+                aoc_common::Error::Argument(format!("day {number} is not solved yet"))
+            })?],
+            Days::All => days::DAYS.to_vec(),
+        };
+
+        let mut mismatches = 0;
+
+        for (i, day) in days.iter().enumerate() {
+            let input = match &args.input {
+                Some(source) => source.read()?,
+                None => aoc_common::read_input(day.input)?,
+            };
+
+            if days.len() > 1 && args.format == Format::Text {
+                if i > 0 {
+                    println!();
+                }
+                println!("day{:02}", day.number);
+            }
+
+            match args.command {
+                Command::Run => mismatches += run(day, &input, &args)?,
+                Command::Bench => bench(day, &input, &args)?,
+            }
+        }
+
+        match mismatches {
+            0 => Ok(()),
+            count => Err(aoc_common::Error::Mismatch(count)),
+        }
+    })
+}
+
+/// Solves `day` once and prints the answers, returning how many of them
+/// differ from the recorded ones.
+fn run(day: &Day, input: &str, args: &Args) -> Result<usize> {
+    let answers = if args.check {
+        Some(Answers::load(Answers::path_for(day.input))?)
+    } else {
+        None
+    };
+    let mut mismatches = 0;
+
+    day.solve(input, &args.parts, args.image, &mut |event| match event {
+        Event::Parsed { elapsed } => {
+            if args.time && args.format == Format::Text {
+                println!("parse: {}", timing::format(elapsed));
+            }
+        }
+        Event::Answered {
+            part,
+            answer,
+            elapsed,
+        } => {
+            let verdict = answers.as_ref().map(|answers| answers.check(part, &answer));
+            if matches!(verdict, Some(Verdict::Fail { .. })) {
+                mismatches += 1;
+            }
+
+            match args.format {
+                Format::Text => print_answer(
+                    part,
+                    &answer,
+                    verdict.as_ref(),
+                    args.time.then_some(elapsed),
+                ),
+                Format::Json => print_json(day, part, &answer, verdict.as_ref(), elapsed),
+            }
+        }
+    })?;
+
+    Ok(mismatches)
+}
+
+/// Prints an answer, optionally with how it compares to the recorded one and
+/// how long it took. Multi-line answers go below the part name.
+fn print_answer(part: Part, answer: &str, verdict: Option<&Verdict>, elapsed: Option<Duration>) {
+    let head = match verdict {
+        Some(verdict) => format!("{part}: {verdict}"),
+        None => format!("{part}:"),
+    };
+    let expected = match verdict {
+        Some(Verdict::Fail { expected }) => Some(expected),
+        _ => None,
+    };
+    let elapsed = elapsed
+        .map(|elapsed| format!(" ({})", timing::format(elapsed)))
+        .unwrap_or_default();
+
+    if answer.contains('\n') || expected.is_some_and(|expected| expected.contains('\n')) {
+        println!("{head}{elapsed}\n{answer}");
+        if let Some(expected) = expected {
+            println!("expected:\n{expected}");
+        }
+    } else {
+        match expected {
+            Some(expected) => println!("{head} {answer}, expected {expected}{elapsed}"),
+            None => println!("{head} {answer}{elapsed}"),
+        }
+    }
+}
+
+/// Prints an answer as a single line of JSON. The verdict is only included
+/// when checking.
+fn print_json(day: &Day, part: Part, answer: &str, verdict: Option<&Verdict>, elapsed: Duration) {
+    let mut object = serde_json::json!({
+        "day": day.number,
+        "part": match part {
+            Part::One => 1,
+            Part::Two => 2,
+        },
+        "answer": answer,
+        "elapsed_ms": elapsed.as_secs_f64() * 1e3,
+    });
+
+    if let Some(verdict) = verdict {
+        object["verdict"] = verdict.to_string().into();
+    }
+
+    println!("{object}");
+}
+
+/// Solves `day` repeatedly and prints the fastest, median and slowest time of
+/// each step.
+fn bench(day: &Day, input: &str, args: &Args) -> Result<()> {
+    let mut parse = vec![];
+    let mut parts: Vec<(Part, Vec<Duration>)> =
+        args.parts.iter().map(|&part| (part, vec![])).collect();
+
+    for _ in 0..args.runs {
+        day.solve(input, &args.parts, false, &mut |event| match event {
+            Event::Parsed { elapsed } => parse.push(elapsed),
+            Event::Answered { part, elapsed, .. } => {
+                if let Some((_, samples)) = parts.iter_mut().find(|(p, _)| *p == part) {
+                    samples.push(elapsed);
+                }
+            }
+        })?;
+    }
+
+    print_stats("parse", &mut parse);
+    for (part, samples) in &mut parts {
+        print_stats(&part.to_string(), samples);
+    }
+
+    Ok(())
+}
+
+fn print_stats(step: &str, samples: &mut [Duration]) {
+    if let Some(Stats { min, median, max }) = Stats::of(samples) {
+        println!(
+            "{step}: min {}, median {}, max {}",
+            timing::format(min),
+            timing::format(median),
+            timing::format(max)
+        );
+    }
+}
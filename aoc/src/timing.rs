@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+/// Formats a duration in the largest unit that keeps it above one, like
+/// `1.52ms` or `830.10µs`.
+pub fn format(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+
+    if seconds >= 1.0 {
+        format!("{seconds:.2}s")
+    } else if seconds >= 1e-3 {
+        format!("{:.2}ms", seconds * 1e3)
+    } else if seconds >= 1e-6 {
+        format!("{:.2}µs", seconds * 1e6)
+    } else {
+        format!("{}ns", duration.as_nanos())
+    }
+}
+
+/// Summary of repeated measurements of the same step.
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn of(samples: &mut [Duration]) -> Option<Stats> {
+        samples.sort_unstable();
+
+        Some(Stats {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
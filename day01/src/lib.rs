@@ -0,0 +1,75 @@
+use std::fmt;
+
+/// Calories of the food items carried by each elf.
+pub type Elf = Vec<u32>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidCalories,
+    NoElves,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidCalories => write!(f, "invalid calories"),
+            ErrorKind::NoElves => write!(f, "expected the calories of at least one elf"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<Vec<Elf>, ParseError> {
+    let groups = aoc_common::numbered_groups(input);
+    if groups.is_empty() {
+        return Err(ParseError::end(ErrorKind::NoElves, input));
+    }
+
+    groups
+        .iter()
+        .map(|elf| {
+            elf.iter()
+                .map(|&(number, line)| {
+                    aoc_common::parse_at(line, ErrorKind::InvalidCalories, number, line)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Most calories carried by one elf, or 0 if there are no elves.
+pub fn part1(input: &[Elf]) -> u32 {
+    input.iter().map(|elf| elf.iter().sum()).max().unwrap_or(0)
+}
+
+pub fn part2(input: &[Elf]) -> u32 {
+    let mut elves: Vec<u32> = input.iter().map(|elf| elf.iter().sum()).collect();
+
+    elves.sort_unstable();
+    elves.reverse();
+
+    elves.iter().take(3).sum()
+}
+
+pub struct Day01;
+
+impl aoc_common::Solution for Day01 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<Elf>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
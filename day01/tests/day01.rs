@@ -0,0 +1,34 @@
+use aoc_common::{answer, Part};
+use day01::{Day01, ErrorKind};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day01>(EXAMPLE, Part::One), "24000");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day01>(EXAMPLE, Part::Two), "45000");
+}
+
+#[test]
+fn parse_errors() {
+    let err = day01::parse("1000\n\nlots").unwrap_err();
+    assert_eq!((err.kind, err.line), (ErrorKind::InvalidCalories, 3));
+    assert_eq!(day01::parse("").unwrap_err().kind, ErrorKind::NoElves);
+    assert_eq!(day01::parse("\n\n").unwrap_err().kind, ErrorKind::NoElves);
+    assert_eq!(day01::part1(&[]), 0);
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day01>(INPUT, Part::One), "71780");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day01>(INPUT, Part::Two), "212489");
+}
@@ -0,0 +1,152 @@
+use std::{cmp::Reverse, fmt};
+
+use crate::{Column, Round, Rules, Shape};
+
+/// The best shape to play against `theirs` and what it scores. Ties go to
+/// the first shape.
+pub fn best_move(rules: &Rules, theirs: Shape) -> (Shape, u32) {
+    rules
+        .shapes()
+        .map(|mine| (mine, rules.score(theirs, mine)))
+        .min_by_key(|&(_, score)| Reverse(score))
+        .expect("a game has shapes")
+}
+
+/// The most I can score in each round.
+pub fn max_scores(rules: &Rules, rounds: &[Round]) -> Vec<u32> {
+    rounds
+        .iter()
+        .map(|&(theirs, _)| best_move(rules, theirs).1)
+        .collect()
+}
+
+/// The most I can score over the whole guide.
+pub fn max_score(rules: &Rules, rounds: &[Round]) -> u32 {
+    max_scores(rules, rounds).iter().sum()
+}
+
+/// A round where the guide doesn't play the best shape.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Suboptimal {
+    /// Index of the round in the guide.
+    pub index: usize,
+    pub played: Shape,
+    pub best: Shape,
+    /// How much more the best shape scores.
+    pub missed: u32,
+}
+
+/// The rounds where following the guide, reading the second column as
+/// `column` says, scores less than it could. `None` if a letter doesn't mean
+/// anything that way.
+pub fn suboptimal(rules: &Rules, rounds: &[Round], column: Column) -> Option<Vec<Suboptimal>> {
+    let mut found = vec![];
+
+    for (index, &(theirs, letter)) in rounds.iter().enumerate() {
+        let played = column.decode(rules, theirs, letter)?;
+        let score = rules.score(theirs, played);
+        let (best, max) = best_move(rules, theirs);
+
+        if score < max {
+            found.push(Suboptimal {
+                index,
+                played,
+                best,
+                missed: max - score,
+            });
+        }
+    }
+
+    Some(found)
+}
+
+/// Why a mapping of the second column's letters can't score a guide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MappingError {
+    /// The mapping doesn't have a shape for every one of my letters.
+    WrongLength { expected: usize, found: usize },
+    /// A letter of the second column isn't one of mine.
+    UnknownLetter(char),
+}
+
+impl fmt::Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MappingError::WrongLength { expected, found } => {
+                write!(
+                    f,
+                    "expected a shape for each of {expected} letters, found {found}"
+                )
+            }
+            MappingError::UnknownLetter(letter) => write!(f, "`{letter}` isn't one of my letters"),
+        }
+    }
+}
+
+impl std::error::Error for MappingError {}
+
+/// My total score when the letters of the second column stand for the
+/// shapes in `mapping`, in the order of the letters.
+pub fn mapped_score(
+    rules: &Rules,
+    rounds: &[Round],
+    mapping: &[Shape],
+) -> Result<u32, MappingError> {
+    if mapping.len() != rules.len() {
+        return Err(MappingError::WrongLength {
+            expected: rules.len(),
+            found: mapping.len(),
+        });
+    }
+
+    rounds
+        .iter()
+        .map(|&(theirs, letter)| {
+            let mine = rules
+                .my_shape(letter)
+                .ok_or(MappingError::UnknownLetter(letter))?;
+
+            Ok(rules.score(theirs, mapping[mine.0]))
+        })
+        .sum()
+}
+
+/// The total score under every way of mapping the letters of the second
+/// column to different shapes, in lexicographic order of the mappings. For
+/// rock paper scissors the first is `X`, `Y`, `Z` as rock, paper and
+/// scissors.
+pub fn all_mappings(
+    rules: &Rules,
+    rounds: &[Round],
+) -> Result<Vec<(Vec<Shape>, u32)>, MappingError> {
+    let mut mapping: Vec<Shape> = rules.shapes().collect();
+    let mut scores = vec![];
+
+    loop {
+        scores.push((mapping.clone(), mapped_score(rules, rounds, &mapping)?));
+
+        if !next_permutation(&mut mapping) {
+            return Ok(scores);
+        }
+    }
+}
+
+/// Rearranges `items` into the next permutation in lexicographic order,
+/// returning `false` once they are in the last one.
+fn next_permutation(items: &mut [Shape]) -> bool {
+    let Some(pivot) = (1..items.len())
+        .rev()
+        .find(|&i| items[i - 1].0 < items[i].0)
+    else {
+        return false;
+    };
+    let swap = (pivot..items.len())
+        .rev()
+        .find(|&i| items[i].0 > items[pivot - 1].0)
+        .expect("the pivot's right neighbour is larger");
+
+    items.swap(pivot - 1, swap);
+    items[pivot..].reverse();
+
+    true
+}
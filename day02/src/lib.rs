@@ -0,0 +1,297 @@
+use std::fmt;
+
+mod analysis;
+
+pub use analysis::{
+    all_mappings, best_move, mapped_score, max_score, max_scores, suboptimal, MappingError,
+    Suboptimal,
+};
+
+/// A shape, by its place in the game's [`Rules`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Shape(pub usize);
+
+impl Shape {
+    pub const ROCK: Shape = Shape(0);
+    pub const PAPER: Shape = Shape(1);
+    pub const SCISSORS: Shape = Shape(2);
+}
+
+/// How a round ends for me.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Lost,
+    Draw,
+    Won,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lost, Outcome::Draw, Outcome::Won];
+}
+
+/// A variant of rock paper scissors: its shapes, what beats what, what each
+/// shape and outcome scores, and the letters standing for the shapes in a
+/// strategy guide.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    /// `outcomes[mine][theirs]`.
+    outcomes: Vec<Vec<Outcome>>,
+    shape_scores: Vec<u32>,
+    /// Indexed by [`Outcome`].
+    outcome_scores: [u32; 3],
+    opponent_letters: Vec<char>,
+    my_letters: Vec<char>,
+}
+
+impl Rules {
+    /// A game where each shape beats the shapes an odd number of places
+    /// before it around the circle of `names`, so every shape beats as many
+    /// shapes as it loses to. Shapes score 1, 2, 3… in order, outcomes 0, 3
+    /// and 6. The opponent's letters start at `A` and mine end at `Z`.
+    ///
+    /// # Panics
+    ///
+    /// If there isn't an odd number of at most 13 shapes.
+    pub fn cyclic(names: &[&str]) -> Rules {
+        let count = names.len();
+        assert!(
+            count % 2 == 1 && count <= 13,
+            "a cyclic game needs an odd number of at most 13 shapes"
+        );
+
+        let outcomes = (0..count)
+            .map(|mine| {
+                (0..count)
+                    .map(|theirs| match (mine + count - theirs) % count {
+                        0 => Outcome::Draw,
+                        distance if distance % 2 == 1 => Outcome::Won,
+                        _ => Outcome::Lost,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Rules {
+            names: names.iter().map(ToString::to_string).collect(),
+            outcomes,
+            shape_scores: (1..=count as u32).collect(),
+            outcome_scores: [0, 3, 6],
+            opponent_letters: ('A'..).take(count).collect(),
+            my_letters: ('A'..='Z').skip(26 - count).collect(),
+        }
+    }
+
+    pub fn rock_paper_scissors() -> Rules {
+        Rules::cyclic(&["rock", "paper", "scissors"])
+    }
+
+    /// The scissors cut paper, paper covers rock, rock crushes lizard… game.
+    pub fn rock_paper_scissors_lizard_spock() -> Rules {
+        Rules::cyclic(&["rock", "paper", "scissors", "spock", "lizard"])
+    }
+
+    /// # Panics
+    ///
+    /// If there isn't a score for every shape.
+    pub fn with_shape_scores(mut self, scores: &[u32]) -> Rules {
+        assert_eq!(scores.len(), self.len(), "expected a score for every shape");
+        self.shape_scores = scores.to_vec();
+
+        self
+    }
+
+    pub fn with_outcome_scores(mut self, lost: u32, draw: u32, won: u32) -> Rules {
+        self.outcome_scores = [lost, draw, won];
+
+        self
+    }
+
+    /// Number of shapes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    pub fn outcome(&self, theirs: Shape, mine: Shape) -> Outcome {
+        self.outcomes[mine.0][theirs.0]
+    }
+
+    /// What I score for a round.
+    pub fn score(&self, theirs: Shape, mine: Shape) -> u32 {
+        self.shape_scores[mine.0] + self.outcome_scores[self.outcome(theirs, mine) as usize]
+    }
+
+    /// The best scoring shape that ends the round with `outcome`, if any.
+    pub fn shape_for(&self, theirs: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .filter(|&mine| self.outcome(theirs, mine) == outcome)
+            .max_by_key(|&mine| self.shape_scores[mine.0])
+    }
+
+    pub fn opponent_shape(&self, letter: char) -> Option<Shape> {
+        self.opponent_letters
+            .iter()
+            .position(|&c| c == letter)
+            .map(Shape)
+    }
+
+    /// The shape a letter of the second column stands for when it means a
+    /// shape.
+    pub fn my_shape(&self, letter: char) -> Option<Shape> {
+        self.my_letters.iter().position(|&c| c == letter).map(Shape)
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::rock_paper_scissors()
+    }
+}
+
+/// What the second column of the strategy guide means.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    /// The shape to play, like the opponent's column.
+    Shapes,
+    /// How the round has to end: `X` to lose, `Y` to draw and `Z` to win.
+    Outcomes,
+}
+
+impl Column {
+    /// The shape to play against `theirs`, if `letter` means anything.
+    pub fn decode(self, rules: &Rules, theirs: Shape, letter: char) -> Option<Shape> {
+        match self {
+            Column::Shapes => rules.my_shape(letter),
+            Column::Outcomes => {
+                let outcome = match letter {
+                    'X' => Outcome::Lost,
+                    'Y' => Outcome::Draw,
+                    'Z' => Outcome::Won,
+                    _ => return None,
+                };
+
+                rules.shape_for(theirs, outcome)
+            }
+        }
+    }
+}
+
+/// The opponent's shape and the letter in the second column.
+pub type Round = (Shape, char);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownShape,
+    MissingSecondColumn,
+    UnknownSecondColumn,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownShape => write!(f, "unknown shape letter, like A, B or C"),
+            ErrorKind::MissingSecondColumn => write!(f, "missing second column"),
+            ErrorKind::UnknownSecondColumn => {
+                write!(f, "unknown second column letter, like X, Y or Z")
+            }
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// Parses a strategy guide using the letters of `rules`.
+pub fn parse_with(input: &str, rules: &Rules) -> Result<Vec<Round>, ParseError> {
+    aoc_common::numbered_lines(input)
+        .map(|(number, line)| {
+            let mut parts = line.split(' ');
+            let opponent = parts.next().unwrap_or_default();
+            let me = parts.next().ok_or_else(|| {
+                ParseError::new(ErrorKind::MissingSecondColumn, number, line.len() + 1, "")
+            })?;
+
+            let letter = |text: &str| {
+                let mut chars = text.chars();
+                chars.next().filter(|_| chars.next().is_none())
+            };
+
+            let Some(opponent) = letter(opponent).and_then(|c| rules.opponent_shape(c)) else {
+                return Err(ParseError::at(
+                    ErrorKind::UnknownShape,
+                    number,
+                    line,
+                    opponent,
+                ));
+            };
+            let Some(me) = letter(me).filter(|&c| rules.my_shape(c).is_some()) else {
+                return Err(ParseError::at(
+                    ErrorKind::UnknownSecondColumn,
+                    number,
+                    line,
+                    me,
+                ));
+            };
+
+            Ok((opponent, me))
+        })
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Round>, ParseError> {
+    parse_with(input, &Rules::default())
+}
+
+/// My total score when following the guide, reading the second column as
+/// `column` says. `None` if a letter doesn't mean anything that way.
+pub fn total_score(rules: &Rules, rounds: &[Round], column: Column) -> Option<u32> {
+    rounds
+        .iter()
+        .map(|&(theirs, letter)| {
+            let mine = column.decode(rules, theirs, letter)?;
+
+            Some(rules.score(theirs, mine))
+        })
+        .sum()
+}
+
+pub fn part1(input: &[Round]) -> u32 {
+    total_score(&Rules::default(), input, Column::Shapes).expect("parse checks the letters")
+}
+
+pub fn part2(input: &[Round]) -> u32 {
+    total_score(&Rules::default(), input, Column::Outcomes).expect("X, Y and Z are outcomes")
+}
+
+pub struct Day02;
+
+impl aoc_common::Solution for Day02 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<Round>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
@@ -0,0 +1,176 @@
+use aoc_common::{answer, Part};
+use day02::{
+    all_mappings, best_move, mapped_score, max_score, max_scores, parse, parse_with, suboptimal,
+    total_score, Column, Day02, ErrorKind, MappingError, Outcome, Rules, Shape, Suboptimal,
+};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day02>(EXAMPLE, Part::One), "15");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day02>(EXAMPLE, Part::Two), "12");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day02>(INPUT, Part::One), "12276");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day02>(INPUT, Part::Two), "9975");
+}
+
+#[test]
+fn rock_paper_scissors_rules() {
+    let rules = Rules::default();
+
+    assert_eq!(rules.outcome(Shape::ROCK, Shape::PAPER), Outcome::Won);
+    assert_eq!(rules.outcome(Shape::ROCK, Shape::SCISSORS), Outcome::Lost);
+    assert_eq!(rules.outcome(Shape::PAPER, Shape::PAPER), Outcome::Draw);
+    assert_eq!(rules.score(Shape::ROCK, Shape::PAPER), 8);
+    assert_eq!(
+        rules.shape_for(Shape::PAPER, Outcome::Lost),
+        Some(Shape::ROCK)
+    );
+}
+
+#[test]
+fn lizard_spock() {
+    let rules = Rules::rock_paper_scissors_lizard_spock();
+    let shape = |name| rules.shapes().find(|&s| rules.name(s) == name).unwrap();
+
+    for (winner, loser) in [
+        ("scissors", "paper"),
+        ("paper", "rock"),
+        ("rock", "lizard"),
+        ("lizard", "spock"),
+        ("spock", "scissors"),
+        ("scissors", "lizard"),
+        ("lizard", "paper"),
+        ("paper", "spock"),
+        ("spock", "rock"),
+        ("rock", "scissors"),
+    ] {
+        assert_eq!(rules.outcome(shape(loser), shape(winner)), Outcome::Won);
+        assert_eq!(rules.outcome(shape(winner), shape(loser)), Outcome::Lost);
+    }
+
+    // Letters A to E for the opponent and V to Z for me, all draws here.
+    let rounds = parse_with("A V\nE Z\nC X", &rules).unwrap();
+    assert_eq!(
+        total_score(&rules, &rounds, Column::Shapes),
+        Some(4 + 8 + 6)
+    );
+    // Winning against scissors with rock or spock, spock scores more.
+    let rounds = parse_with("C Z", &rules).unwrap();
+    assert_eq!(total_score(&rules, &rounds, Column::Outcomes), Some(4 + 6));
+    // V doesn't mean an outcome.
+    let rounds = parse_with("A V", &rules).unwrap();
+    assert_eq!(total_score(&rules, &rounds, Column::Outcomes), None);
+    assert!(parse("A V").is_err());
+}
+
+#[test]
+fn custom_scores() {
+    let rules = Rules::default()
+        .with_shape_scores(&[10, 20, 30])
+        .with_outcome_scores(1, 2, 4);
+    let rounds = parse_with(EXAMPLE, &rules).unwrap();
+
+    assert_eq!(
+        total_score(&rules, &rounds, Column::Shapes),
+        Some(24 + 11 + 32)
+    );
+    assert_eq!(
+        total_score(&rules, &rounds, Column::Outcomes),
+        Some(12 + 11 + 14)
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let err = parse("A Y\nD X").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::UnknownShape);
+    assert_eq!(err.line, 2);
+    assert_eq!(parse("A").unwrap_err().kind, ErrorKind::MissingSecondColumn);
+    assert_eq!(
+        parse("A XY").unwrap_err().kind,
+        ErrorKind::UnknownSecondColumn
+    );
+}
+
+#[test]
+fn best_scores() {
+    let rules = Rules::default();
+    let rounds = parse(EXAMPLE).unwrap();
+
+    assert_eq!(best_move(&rules, Shape::SCISSORS), (Shape::ROCK, 7));
+    assert_eq!(max_scores(&rules, &rounds), [8, 9, 7]);
+    assert_eq!(max_score(&rules, &rounds), 24);
+}
+
+#[test]
+fn suboptimal_rounds() {
+    let rules = Rules::default();
+    let rounds = parse(EXAMPLE).unwrap();
+
+    assert_eq!(
+        suboptimal(&rules, &rounds, Column::Shapes).unwrap(),
+        [
+            Suboptimal {
+                index: 1,
+                played: Shape::ROCK,
+                best: Shape::SCISSORS,
+                missed: 8
+            },
+            Suboptimal {
+                index: 2,
+                played: Shape::SCISSORS,
+                best: Shape::ROCK,
+                missed: 1
+            },
+        ]
+    );
+    let found = suboptimal(&rules, &rounds, Column::Outcomes).unwrap();
+    assert_eq!(
+        found
+            .iter()
+            .map(|s| (s.index, s.missed))
+            .collect::<Vec<_>>(),
+        [(0, 4), (1, 8)]
+    );
+}
+
+#[test]
+fn letter_mappings() {
+    let rules = Rules::default();
+    let rounds = parse(EXAMPLE).unwrap();
+    let (rock, paper, scissors) = (Shape::ROCK, Shape::PAPER, Shape::SCISSORS);
+
+    let scores = all_mappings(&rules, &rounds).unwrap();
+    assert_eq!(scores.len(), 6);
+    assert_eq!(scores[0], (vec![rock, paper, scissors], 15));
+    assert_eq!(scores[1], (vec![rock, scissors, paper], 6));
+    assert_eq!(scores[3], (vec![paper, scissors, rock], 15));
+    assert_eq!(
+        mapped_score(&rules, &rounds, &[scissors, rock, paper]),
+        Ok(scores[4].1)
+    );
+    assert_eq!(
+        mapped_score(&rules, &rounds, &[scissors, rock]),
+        Err(MappingError::WrongLength {
+            expected: 3,
+            found: 2
+        })
+    );
+
+    let rules = Rules::rock_paper_scissors_lizard_spock();
+    assert_eq!(all_mappings(&rules, &[]).unwrap().len(), 120);
+}
@@ -0,0 +1,20 @@
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day03::Day03;
+
+const INPUT: &str = include_str!("../input");
+
+fn bench(c: &mut Criterion) {
+    let input = Day03::parse(INPUT).unwrap();
+
+    c.bench_function("day03 parse", |b| b.iter(|| Day03::parse(black_box(INPUT))));
+    c.bench_function("day03 part1", |b| {
+        b.iter(|| Day03::part1(black_box(&input)).map(|answer| answer.to_string()))
+    });
+    c.bench_function("day03 part2", |b| {
+        b.iter(|| Day03::part2(black_box(&input)).map(|answer| answer.to_string()))
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+use std::{fmt, ops};
+
+use crate::{get_priority, item};
+
+/// A set of items, one bit per priority.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Items(u64);
+
+impl Items {
+    pub const EMPTY: Items = Items(0);
+    /// Every letter.
+    pub const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    /// The items in `text`, or `None` if it has anything but letters.
+    pub fn new(text: &str) -> Option<Items> {
+        text.chars().try_fold(Items::EMPTY, |items, c| {
+            Some(Items(items.0 | 1 << get_priority(c)?))
+        })
+    }
+
+    pub fn contains(self, item: char) -> bool {
+        get_priority(item).is_some_and(|priority| self.0 & 1 << priority != 0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items, by priority.
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        (1..=52)
+            .filter(move |priority| self.0 & 1 << priority != 0)
+            .filter_map(item)
+    }
+
+    /// The only item, if there is exactly one.
+    pub fn single(self) -> Option<char> {
+        if self.len() == 1 {
+            item(self.0.trailing_zeros())
+        } else {
+            None
+        }
+    }
+}
+
+impl ops::BitAnd for Items {
+    type Output = Items;
+
+    fn bitand(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+}
+
+impl ops::BitOr for Items {
+    type Output = Items;
+
+    fn bitor(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+}
+
+impl fmt::Debug for Items {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Items(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter().try_for_each(|item| write!(f, "{item}"))
+    }
+}
@@ -0,0 +1,247 @@
+use std::fmt;
+
+mod items;
+
+pub use items::Items;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidItem,
+    OddLength,
+    NoCommonItem,
+    MultipleCommonItems,
+    IncompleteGroup,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidItem => write!(f, "invalid item, expected a letter"),
+            ErrorKind::OddLength => write!(f, "odd number of items, expected two halves"),
+            ErrorKind::NoCommonItem => write!(f, "no item in common"),
+            ErrorKind::MultipleCommonItems => write!(f, "more than one item in common"),
+            ErrorKind::IncompleteGroup => write!(f, "group of fewer than three rucksacks"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// The items in each rucksack, one line per rucksack, only checking that
+/// they are letters.
+pub fn parse_items(input: &str) -> Result<Vec<String>, ParseError> {
+    aoc_common::numbered_lines(input)
+        .map(
+            |(number, line)| match line.find(|c: char| !c.is_ascii_alphabetic()) {
+                Some(i) => {
+                    let item = &line[i..i + line[i..].chars().next().unwrap().len_utf8()];
+
+                    Err(ParseError::at(ErrorKind::InvalidItem, number, line, item))
+                }
+                None => Ok(line.to_owned()),
+            },
+        )
+        .collect()
+}
+
+/// Points at the line of the rucksack with the problem.
+fn parse_error(rucksacks: &[String], err: RucksackError) -> ParseError {
+    let kind = match err.problem {
+        Problem::InvalidItem => ErrorKind::InvalidItem,
+        Problem::UnevenCompartments => ErrorKind::OddLength,
+        Problem::NoCommonItem => ErrorKind::NoCommonItem,
+        Problem::MultipleCommonItems(_) => ErrorKind::MultipleCommonItems,
+        Problem::IncompleteGroup => ErrorKind::IncompleteGroup,
+    };
+
+    ParseError::line(kind, err.index + 1, &rucksacks[err.index])
+}
+
+/// The items in each rucksack, checking that every rucksack has one item in
+/// both halves. Groups are left to [`check_groups`] and [`part2`], as only
+/// part 2 needs them.
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let rucksacks = parse_items(input)?;
+
+    misplaced_items(&rucksacks, 2).map_err(|err| parse_error(&rucksacks, err))?;
+
+    Ok(rucksacks)
+}
+
+/// Checks that every group of three rucksacks has one item in common.
+pub fn check_groups(rucksacks: &[String]) -> Result<(), ParseError> {
+    badges(rucksacks, 3)
+        .map(|_| ())
+        .map_err(|err| parse_error(rucksacks, err))
+}
+
+/// Priority of an item, from 1 for `a` to 52 for `Z`.
+pub fn get_priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// The item with a priority.
+pub fn item(priority: u32) -> Option<char> {
+    match priority {
+        1..=26 => char::from_u32('a' as u32 + priority - 1),
+        27..=52 => char::from_u32('A' as u32 + priority - 27),
+        _ => None,
+    }
+}
+
+/// Why rucksacks don't have the one item in common they should.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Problem {
+    /// The rucksack has something other than letters.
+    InvalidItem,
+    /// The items can't be split into compartments of the same size.
+    UnevenCompartments,
+    NoCommonItem,
+    MultipleCommonItems(Items),
+    /// The last group has fewer rucksacks than the others.
+    IncompleteGroup,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::InvalidItem => write!(f, "items that aren't letters"),
+            Problem::UnevenCompartments => write!(f, "items don't split into equal compartments"),
+            Problem::NoCommonItem => write!(f, "no item in common"),
+            Problem::MultipleCommonItems(items) => write!(f, "items `{items}` in common"),
+            Problem::IncompleteGroup => write!(f, "incomplete group"),
+        }
+    }
+}
+
+/// The first rucksack, or the first rucksack of the first group, with a
+/// [`Problem`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RucksackError {
+    /// Index of the rucksack in the list.
+    pub index: usize,
+    pub problem: Problem,
+}
+
+impl fmt::Display for RucksackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rucksack {}: {}", self.index + 1, self.problem)
+    }
+}
+
+impl std::error::Error for RucksackError {}
+
+/// The one item every set has.
+pub fn common_item(sets: impl IntoIterator<Item = Items>) -> Result<char, Problem> {
+    let common = sets
+        .into_iter()
+        .fold(Items::ALL, |common, items| common & items);
+
+    match common.len() {
+        0 => Err(Problem::NoCommonItem),
+        1 => Ok(common.single().unwrap()),
+        _ => Err(Problem::MultipleCommonItems(common)),
+    }
+}
+
+/// The items of a rucksack split into `count` compartments of the same
+/// size.
+pub fn compartments(rucksack: &str, count: usize) -> Result<Vec<Items>, Problem> {
+    // Only letters, so every item takes one byte.
+    if Items::new(rucksack).is_none() {
+        return Err(Problem::InvalidItem);
+    }
+    if count == 0 || !rucksack.len().is_multiple_of(count) {
+        return Err(Problem::UnevenCompartments);
+    }
+
+    let size = rucksack.len() / count;
+    (0..count)
+        .map(|i| Items::new(&rucksack[i * size..(i + 1) * size]).ok_or(Problem::InvalidItem))
+        .collect()
+}
+
+/// The item in all `count` compartments of each rucksack.
+pub fn misplaced_items(rucksacks: &[String], count: usize) -> Result<Vec<char>, RucksackError> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(index, rucksack)| {
+            compartments(rucksack, count)
+                .and_then(common_item)
+                .map_err(|problem| RucksackError { index, problem })
+        })
+        .collect()
+}
+
+/// The item all rucksacks of each group of `size` have in common.
+///
+/// # Panics
+///
+/// If `size` is zero.
+pub fn badges(rucksacks: &[String], size: usize) -> Result<Vec<char>, RucksackError> {
+    rucksacks
+        .chunks(size)
+        .enumerate()
+        .map(|(group, rucksacks)| {
+            let error = |offset, problem| RucksackError {
+                index: group * size + offset,
+                problem,
+            };
+
+            if rucksacks.len() < size {
+                return Err(error(0, Problem::IncompleteGroup));
+            }
+
+            let items = rucksacks
+                .iter()
+                .enumerate()
+                .map(|(offset, rucksack)| {
+                    Items::new(rucksack).ok_or_else(|| error(offset, Problem::InvalidItem))
+                })
+                .collect::<Result<Vec<Items>, _>>()?;
+
+            common_item(items).map_err(|problem| error(0, problem))
+        })
+        .collect()
+}
+
+/// Total priority of the items.
+pub fn priority_sum(items: &[char]) -> u32 {
+    items.iter().filter_map(|&item| get_priority(item)).sum()
+}
+
+pub fn part1(input: &[String]) -> u32 {
+    priority_sum(&misplaced_items(input, 2).expect("parse checks the compartments"))
+}
+
+/// Total priority of the badges, or the first group of three without one.
+pub fn part2(input: &[String]) -> Result<u32, RucksackError> {
+    badges(input, 3).map(|badges| priority_sum(&badges))
+}
+
+pub struct Day03;
+
+impl aoc_common::Solution for Day03 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<String>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        part2(input).map_err(|err| parse_error(input, err))
+    }
+}
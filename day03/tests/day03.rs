@@ -0,0 +1,147 @@
+use aoc_common::{answer, Part};
+use day03::{
+    badges, get_priority, item, misplaced_items, parse, parse_items, priority_sum, Day03,
+    ErrorKind, Items, Problem, RucksackError,
+};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day03>(EXAMPLE, Part::One), "157");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day03>(EXAMPLE, Part::Two), "70");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day03>(INPUT, Part::One), "8088");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day03>(INPUT, Part::Two), "2522");
+}
+
+#[test]
+fn priorities() {
+    assert_eq!(get_priority('a'), Some(1));
+    assert_eq!(get_priority('Z'), Some(52));
+    assert_eq!(get_priority('1'), None);
+    assert_eq!(get_priority('é'), None);
+    assert_eq!(item(27), Some('A'));
+    assert_eq!(item(0), None);
+    assert_eq!(item(53), None);
+}
+
+#[test]
+fn item_sets() {
+    let items = Items::new("vJrwpWtwJgWr").unwrap();
+
+    assert_eq!(items.len(), 8);
+    assert!(items.contains('J') && !items.contains('j'));
+    assert_eq!(items.to_string(), "gprtvwJW");
+    assert_eq!(Items::new("ab1"), None);
+    assert_eq!(Items::ALL.len(), 52);
+    assert_eq!(
+        (items & Items::new("hcsFMMfFFhFp").unwrap()).single(),
+        Some('p')
+    );
+}
+
+#[test]
+fn misplaced_and_badges() {
+    let rucksacks = parse(EXAMPLE).unwrap();
+
+    assert_eq!(
+        misplaced_items(&rucksacks, 2).unwrap(),
+        ['p', 'L', 'P', 'v', 't', 's']
+    );
+    assert_eq!(badges(&rucksacks, 3).unwrap(), ['r', 'Z']);
+    assert_eq!(priority_sum(&['p', 'L']), 16 + 38);
+}
+
+#[test]
+fn any_number_of_compartments_and_group_size() {
+    // Compartments `abx`, `cdx` and `efx`, then `ax`, `bx` and `cy`.
+    let rucksacks = parse_items("abxcdxefx\naxbxcy\naxcyz").unwrap();
+
+    assert_eq!(misplaced_items(&rucksacks[..1], 3).unwrap(), ['x']);
+    assert_eq!(
+        misplaced_items(&rucksacks, 3).unwrap_err(),
+        RucksackError {
+            index: 1,
+            problem: Problem::NoCommonItem
+        }
+    );
+    assert_eq!(
+        misplaced_items(&rucksacks[2..], 2).unwrap_err().problem,
+        Problem::UnevenCompartments
+    );
+    assert_eq!(
+        badges(&rucksacks, 3).unwrap_err().problem,
+        Problem::MultipleCommonItems(Items::new("acx").unwrap())
+    );
+
+    let rucksacks = parse_items("ab\nbc\nbd\nbe\nfg").unwrap();
+    assert_eq!(badges(&rucksacks[..4], 4).unwrap(), ['b']);
+    assert_eq!(
+        badges(&rucksacks, 4).unwrap_err(),
+        RucksackError {
+            index: 4,
+            problem: Problem::IncompleteGroup
+        }
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let err = parse("vJrwpWtwJgWrhcsFMMfFFhFp\nabc").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::OddLength);
+    assert_eq!(err.line, 2);
+    assert_eq!(parse("ab").unwrap_err().kind, ErrorKind::NoCommonItem);
+    assert_eq!(
+        parse("abab").unwrap_err().kind,
+        ErrorKind::MultipleCommonItems
+    );
+    assert_eq!(parse("a1").unwrap_err().kind, ErrorKind::InvalidItem);
+
+    // Only part 2 needs whole groups.
+    assert_eq!(answer::<Day03>("aa\naa", Part::One), "2");
+    assert_eq!(
+        day03::part2(&parse("aa\naa").unwrap()).unwrap_err().problem,
+        Problem::IncompleteGroup
+    );
+    let err = day03::check_groups(&parse("aa\naa").unwrap()).unwrap_err();
+    assert_eq!((err.kind, err.line), (ErrorKind::IncompleteGroup, 1));
+    assert!(aoc_common::solve::<Day03>("aa\naa", &[Part::Two], false, &mut |_| {}).is_err());
+}
+
+#[test]
+fn items_that_arent_letters() {
+    assert_eq!(day03::compartments("aéb", 2), Err(Problem::InvalidItem));
+    assert_eq!(
+        day03::compartments("abc", 2),
+        Err(Problem::UnevenCompartments)
+    );
+
+    let rucksacks = ["aa", "aé", "ac"].map(String::from);
+    assert_eq!(
+        misplaced_items(&rucksacks, 2).unwrap_err(),
+        RucksackError {
+            index: 1,
+            problem: Problem::InvalidItem
+        }
+    );
+    assert_eq!(
+        badges(&rucksacks, 3).unwrap_err(),
+        RucksackError {
+            index: 1,
+            problem: Problem::InvalidItem
+        }
+    );
+}
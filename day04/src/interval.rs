@@ -0,0 +1,234 @@
+use std::fmt;
+
+/// Sections from `start` to `end`, inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    start: u32,
+    end: u32,
+}
+
+impl Interval {
+    /// `None` if `end` comes before `start`.
+    pub fn new(start: u32, end: u32) -> Option<Interval> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn start(self) -> u32 {
+        self.start
+    }
+
+    pub fn end(self) -> u32 {
+        self.end
+    }
+
+    /// Number of sections.
+    pub fn size(self) -> u64 {
+        u64::from(self.end - self.start) + 1
+    }
+
+    pub fn contains(self, section: u32) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    /// Whether every section of `other` is in this interval.
+    pub fn covers(self, other: Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(self, other: Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// The sections in both intervals, if any.
+    pub fn intersection(self, other: Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The sections in either interval, if they overlap or touch so that
+    /// they make up one interval.
+    pub fn union(self, other: Interval) -> Option<Interval> {
+        let touch =
+            self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1);
+
+        touch.then(|| Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// The sections of this interval that aren't in `other`.
+    pub fn difference(self, other: Interval) -> IntervalSet {
+        IntervalSet::from(self).difference(&IntervalSet::from(other))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Any set of sections, as the fewest intervals, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    /// Neither overlapping nor touching.
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of sections.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(|interval| interval.size()).sum()
+    }
+
+    /// From the first section to the last, if there are any.
+    pub fn span(&self) -> Option<Interval> {
+        let first = self.intervals.first()?;
+        let last = self.intervals.last()?;
+
+        Interval::new(first.start, last.end)
+    }
+
+    pub fn contains(&self, section: u32) -> bool {
+        self.find(section).is_ok()
+    }
+
+    /// Whether every section of `interval` is in the set.
+    pub fn covers(&self, interval: Interval) -> bool {
+        self.find(interval.start)
+            .is_ok_and(|i| self.intervals[i].covers(interval))
+    }
+
+    /// Index of the interval with `section`, or where it would go.
+    fn find(&self, section: u32) -> Result<usize, usize> {
+        self.intervals.binary_search_by(|interval| {
+            if interval.end < section {
+                std::cmp::Ordering::Less
+            } else if interval.start > section {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // Everything that overlaps or touches `interval` is in `first..last`.
+        let first = self
+            .intervals
+            .partition_point(|other| other.end.saturating_add(1) < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end.saturating_add(1));
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, &other| merged.union(other).unwrap());
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&a), Some(&b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        self.intersection(&other.complement())
+    }
+
+    /// Every section that isn't in the set.
+    pub fn complement(&self) -> IntervalSet {
+        let mut intervals = vec![];
+        let mut next = Some(0);
+
+        for interval in &self.intervals {
+            if let Some(start) = next.filter(|&start| start < interval.start) {
+                intervals.push(Interval {
+                    start,
+                    end: interval.start - 1,
+                });
+            }
+            next = interval.end.checked_add(1);
+        }
+        if let Some(start) = next {
+            intervals.push(Interval {
+                start,
+                end: u32::MAX,
+            });
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// The sections between the first and the last that aren't in the set.
+    pub fn gaps(&self) -> IntervalSet {
+        match self.span() {
+            Some(span) => IntervalSet::from(span).difference(self),
+            None => IntervalSet::new(),
+        }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+
+        set
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{interval}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use std::fmt;
+
+mod interval;
+
+pub use interval::{Interval, IntervalSet};
+
+/// The sections an elf is assigned.
+pub type Range = Interval;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingComma,
+    MissingDash,
+    InvalidSection,
+    BackwardsRange,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingComma => write!(f, "expected two ranges separated by `,`"),
+            ErrorKind::MissingDash => write!(f, "expected a range like `2-4`"),
+            ErrorKind::InvalidSection => write!(f, "invalid section number"),
+            ErrorKind::BackwardsRange => write!(f, "range ends before it starts"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+fn parse_range(range: &str, number: usize, line: &str) -> Result<Range, ParseError> {
+    let (first, last) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(ErrorKind::MissingDash, number, line, range))?;
+
+    Interval::new(
+        aoc_common::parse_at(first, ErrorKind::InvalidSection, number, line)?,
+        aoc_common::parse_at(last, ErrorKind::InvalidSection, number, line)?,
+    )
+    .ok_or_else(|| ParseError::at(ErrorKind::BackwardsRange, number, line, range))
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Range, Range)>, ParseError> {
+    aoc_common::numbered_lines(input)
+        .map(|(number, line)| {
+            let (left, right) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::line(ErrorKind::MissingComma, number, line))?;
+
+            Ok((
+                parse_range(left, number, line)?,
+                parse_range(right, number, line)?,
+            ))
+        })
+        .collect()
+}
+
+/// Every section assigned to anyone.
+pub fn assigned(input: &[(Range, Range)]) -> IntervalSet {
+    input.iter().flat_map(|&(a, b)| [a, b]).collect()
+}
+
+/// Number of sections assigned to anyone.
+pub fn covered(input: &[(Range, Range)]) -> u64 {
+    assigned(input).len()
+}
+
+/// The sections nobody is assigned between the first and the last assigned.
+pub fn uncovered(input: &[(Range, Range)]) -> IntervalSet {
+    assigned(input).gaps()
+}
+
+/// The section assigned to the most elves and how many, the first of them
+/// on a tie. `None` if nobody is assigned anything.
+pub fn most_overlapped(input: &[(Range, Range)]) -> Option<(u32, usize)> {
+    // +1 where an assignment starts and -1 just after it ends, with the
+    // ends first at the same section so that they don't count there.
+    let mut events: Vec<(u64, bool)> = input
+        .iter()
+        .flat_map(|&(a, b)| [a, b])
+        .flat_map(|range| {
+            [
+                (u64::from(range.start()), true),
+                (u64::from(range.end()) + 1, false),
+            ]
+        })
+        .collect();
+    events.sort_unstable();
+
+    let mut count = 0;
+    let mut best: Option<(u32, usize)> = None;
+    for (section, starts) in events {
+        if starts {
+            count += 1;
+            if best.is_none_or(|(_, most)| count > most) {
+                best = Some((section as u32, count));
+            }
+        } else {
+            count -= 1;
+        }
+    }
+
+    best
+}
+
+pub fn part1(input: &[(Range, Range)]) -> usize {
+    input
+        .iter()
+        .filter(|&&(a, b)| a.covers(b) || b.covers(a))
+        .count()
+}
+
+pub fn part2(input: &[(Range, Range)]) -> usize {
+    input.iter().filter(|&&(a, b)| a.overlaps(b)).count()
+}
+
+pub struct Day04;
+
+impl aoc_common::Solution for Day04 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<(Range, Range)>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
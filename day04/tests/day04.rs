@@ -0,0 +1,101 @@
+use aoc_common::{answer, Part};
+use day04::{covered, most_overlapped, parse, uncovered, Day04, ErrorKind, Interval, IntervalSet};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day04>(EXAMPLE, Part::One), "2");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day04>(EXAMPLE, Part::Two), "4");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day04>(INPUT, Part::One), "560");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day04>(INPUT, Part::Two), "839");
+}
+
+fn interval(start: u32, end: u32) -> Interval {
+    Interval::new(start, end).unwrap()
+}
+
+#[test]
+fn intervals() {
+    let a = interval(2, 6);
+    let b = interval(4, 8);
+
+    assert_eq!(Interval::new(3, 2), None);
+    assert_eq!(a.size(), 5);
+    assert!(a.overlaps(b) && !a.covers(b) && interval(1, 9).covers(b));
+    assert_eq!(a.intersection(b), Some(interval(4, 6)));
+    assert_eq!(a.intersection(interval(7, 9)), None);
+    assert_eq!(a.union(interval(7, 9)), Some(interval(2, 9)));
+    assert_eq!(a.union(interval(8, 9)), None);
+    assert_eq!(a.difference(interval(3, 4)).to_string(), "2-2,5-6");
+    assert_eq!(interval(0, u32::MAX).size(), 1 << 32);
+}
+
+#[test]
+fn interval_sets() {
+    let set: IntervalSet = [
+        interval(10, 12),
+        interval(1, 3),
+        interval(4, 5),
+        interval(20, 30),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.to_string(), "1-5,10-12,20-30");
+    assert_eq!(set.len(), 5 + 3 + 11);
+    assert!(set.contains(11) && !set.contains(13));
+    assert!(set.covers(interval(2, 4)) && !set.covers(interval(4, 10)));
+    assert_eq!(set.span(), Some(interval(1, 30)));
+    assert_eq!(set.gaps().to_string(), "6-9,13-19");
+
+    let other: IntervalSet = [interval(3, 11), interval(25, 40)].into_iter().collect();
+    assert_eq!(set.union(&other).to_string(), "1-12,20-40");
+    assert_eq!(set.intersection(&other).to_string(), "3-5,10-11,25-30");
+    assert_eq!(set.difference(&other).to_string(), "1-2,12-12,20-24");
+    assert_eq!(
+        other.complement().to_string(),
+        format!("0-2,12-24,41-{}", u32::MAX)
+    );
+    assert!(IntervalSet::new().gaps().is_empty());
+}
+
+#[test]
+fn reports() {
+    let input = parse(EXAMPLE).unwrap();
+
+    assert_eq!(covered(&input), 8);
+    assert!(uncovered(&input).is_empty());
+    assert_eq!(most_overlapped(&input), Some((6, 8)));
+
+    let input = parse("1-3,10-12\n5-5,20-20").unwrap();
+    assert_eq!(covered(&input), 8);
+    assert_eq!(uncovered(&input).to_string(), "4-4,6-9,13-19");
+    assert_eq!(uncovered(&input).len(), 12);
+    assert_eq!(most_overlapped(&input), Some((1, 1)));
+    assert_eq!(most_overlapped(&[]), None);
+}
+
+#[test]
+fn wide_sections() {
+    let input = parse("1000-70000,69000-100000").unwrap();
+
+    assert_eq!(answer::<Day04>("1000-70000,69000-100000", Part::Two), "1");
+    assert_eq!(most_overlapped(&input), Some((69000, 2)));
+    assert_eq!(
+        parse("4-2,1-1").unwrap_err().kind,
+        ErrorKind::BackwardsRange
+    );
+}
@@ -0,0 +1,97 @@
+//! Carries out day 05's moves with any crane, drawing the stacks like the
+//! input does. With `--undo` the drawing is taken as the state after the
+//! moves, which are undone last one first to find the start.
+
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::{Error, Result, Solution, Source};
+use day05::{Crane, CrateMover9000, CrateMover9001, Day05, Simulator, UpTo};
+
+const USAGE: &str = "stacks [--crane <9000 | 9001 | N>] [--steps] [--undo] [INPUT | -]";
+
+struct Args {
+    crane: Box<dyn Crane>,
+    /// Draw the stacks after every move, not just the last one.
+    steps: bool,
+    /// Undo the moves from the drawn stacks instead of carrying them out.
+    undo: bool,
+    input: Source,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args> {
+    let mut parsed = Args {
+        crane: Box::new(CrateMover9000),
+        steps: false,
+        undo: false,
+        input: Source::File(PathBuf::from(Day05::INPUT)),
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--steps" => parsed.steps = true,
+            "--undo" => parsed.undo = true,
+            "--crane" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| Error::Argument(String::from("missing value for `--crane`")))?;
+                parsed.crane = match value.as_str() {
+                    "9000" => Box::new(CrateMover9000),
+                    "9001" => Box::new(CrateMover9001),
+                    _ => match value.parse() {
+                        Ok(capacity @ 1..) => Box::new(UpTo(capacity)),
+                        _ => {
+                            return Err(Error::Argument(format!(
+                                "unknown crane `{value}`, expected 9000, 9001 or how many crates it lifts at once"
+                            )))
+                        }
+                    },
+                };
+            }
+            input if !input.starts_with("--") => parsed.input = Source::from_arg(input),
+            _ => return Err(Error::Usage(USAGE.to_string())),
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn main() -> ExitCode {
+    aoc_common::run(|| {
+        let args = parse_args(std::env::args().skip(1))?;
+        let input = args.input.read()?;
+
+        if args.undo {
+            let (state, moves) =
+                day05::parse_unchecked(&input).map_err(|err| Error::Parse(Box::new(err)))?;
+            day05::validate_undo(&state, &moves).map_err(|err| Error::Parse(Box::new(err)))?;
+
+            let mut simulator = Simulator::finished(args.crane, state, &moves);
+            if args.steps {
+                print!("{}", day05::render(simulator.state()));
+                while let Some(mv) = simulator.undo() {
+                    print!("\nundo {mv}\n\n{}", day05::render(simulator.state()));
+                }
+            } else {
+                print!("{}", day05::render(simulator.rewind()));
+            }
+            println!("\ntop: {}", day05::top_crates(simulator.state()));
+
+            return Ok(());
+        }
+
+        let (state, moves) = Day05::parse(&input).map_err(|err| Error::Parse(Box::new(err)))?;
+
+        let mut simulator = Simulator::new(args.crane, state, &moves);
+        if args.steps {
+            print!("{}", day05::render(simulator.state()));
+            while let Some(mv) = simulator.step() {
+                print!("\n{mv}\n\n{}", day05::render(simulator.state()));
+            }
+        } else {
+            print!("{}", day05::render(simulator.run()));
+        }
+        println!("\ntop: {}", day05::top_crates(simulator.state()));
+
+        Ok(())
+    })
+}
@@ -0,0 +1,170 @@
+use crate::{Crate, Move, State};
+
+/// How a crane carries out a move.
+pub trait Crane {
+    /// The most crates the crane lifts at once, keeping their order.
+    fn capacity(&self) -> usize;
+
+    /// Carries out `mv` by lifting as many crates as it can from the top of
+    /// the source stack onto the target stack until all are moved. The move
+    /// needs to be [valid](crate::validate), as [`parse`](crate::parse)
+    /// checks.
+    fn apply(&self, mv: &Move, state: &mut State) {
+        for lifted in lifts(self.capacity(), mv.quantity) {
+            lift(state, lifted, mv.source, mv.target);
+        }
+    }
+
+    /// Puts the crates `mv` moved back where they came from, lifting them in
+    /// the same groups as [`apply`](Crane::apply) but the other way around.
+    /// The move needs to be [undoable](crate::validate_undo).
+    fn undo(&self, mv: &Move, state: &mut State) {
+        for lifted in lifts(self.capacity(), mv.quantity).into_iter().rev() {
+            lift(state, lifted, mv.target, mv.source);
+        }
+    }
+}
+
+/// How many crates each lift of a move takes.
+fn lifts(capacity: usize, quantity: usize) -> Vec<usize> {
+    let capacity = capacity.max(1);
+    let mut lifts = vec![capacity; quantity / capacity];
+    let rest = quantity % capacity;
+    if rest > 0 {
+        lifts.push(rest);
+    }
+
+    lifts
+}
+
+/// Moves the top `count` crates of stack `from` onto `to`, keeping their
+/// order.
+fn lift(state: &mut State, count: usize, from: usize, to: usize) {
+    let crates: Vec<Crate> = state[from].drain(0..count).collect();
+    for &c in crates.iter().rev() {
+        state[to].push_front(c);
+    }
+}
+
+/// A crane picked at runtime.
+impl<C: Crane + ?Sized> Crane for Box<C> {
+    fn capacity(&self) -> usize {
+        self.as_ref().capacity()
+    }
+
+    fn apply(&self, mv: &Move, state: &mut State) {
+        self.as_ref().apply(mv, state);
+    }
+
+    fn undo(&self, mv: &Move, state: &mut State) {
+        self.as_ref().undo(mv, state);
+    }
+}
+
+/// Moves one crate at a time, so a move reverses the order of its crates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn capacity(&self) -> usize {
+        1
+    }
+}
+
+/// Moves all crates of a move at once, keeping their order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+}
+
+/// Moves up to the given number of crates at once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpTo(pub usize);
+
+impl Crane for UpTo {
+    fn capacity(&self) -> usize {
+        self.0
+    }
+}
+
+/// Carries out moves one at a time.
+pub struct Simulator<'a, C> {
+    crane: C,
+    state: State,
+    moves: &'a [Move],
+    /// Number of moves carried out so far.
+    done: usize,
+}
+
+impl<'a, C: Crane> Simulator<'a, C> {
+    pub fn new(crane: C, state: State, moves: &'a [Move]) -> Simulator<'a, C> {
+        Simulator {
+            crane,
+            state,
+            moves,
+            done: 0,
+        }
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Picks up after all `moves` were carried out, ending in `state`, to
+    /// [undo](Simulator::undo) them.
+    pub fn finished(crane: C, state: State, moves: &'a [Move]) -> Simulator<'a, C> {
+        Simulator {
+            crane,
+            state,
+            moves,
+            done: moves.len(),
+        }
+    }
+
+    /// Number of moves carried out so far.
+    pub fn done(&self) -> usize {
+        self.done
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done == self.moves.len()
+    }
+
+    /// Carries out the next move and returns it, or `None` once all moves
+    /// are done.
+    pub fn step(&mut self) -> Option<&'a Move> {
+        let mv = self.moves.get(self.done)?;
+        self.crane.apply(mv, &mut self.state);
+        self.done += 1;
+
+        Some(mv)
+    }
+
+    /// Carries out all remaining moves.
+    pub fn run(&mut self) -> &State {
+        while self.step().is_some() {}
+
+        &self.state
+    }
+
+    /// Undoes the last move carried out and returns it, or `None` if there
+    /// is none.
+    pub fn undo(&mut self) -> Option<&'a Move> {
+        self.done = self.done.checked_sub(1)?;
+        let mv = &self.moves[self.done];
+        self.crane.undo(mv, &mut self.state);
+
+        Some(mv)
+    }
+
+    /// Undoes every move carried out, back to the starting state.
+    pub fn rewind(&mut self) -> &State {
+        while self.undo().is_some() {}
+
+        &self.state
+    }
+}
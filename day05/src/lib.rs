@@ -0,0 +1,266 @@
+use std::{collections::VecDeque, fmt};
+
+mod crane;
+mod validate;
+
+pub use crane::{Crane, CrateMover9000, CrateMover9001, Simulator, UpTo};
+pub use validate::{validate, validate_undo, IllegalMove, Problem};
+
+/// Moves `quantity` crates from stack `source` to stack `target`, both
+/// counted from zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub quantity: usize,
+    pub source: usize,
+    pub target: usize,
+}
+
+/// Written like in the input, with stacks counted from one.
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.quantity,
+            self.source + 1,
+            self.target + 1
+        )
+    }
+}
+
+pub type Crate = char;
+
+/// Every stack with its top crate at the front.
+pub type State = Vec<VecDeque<Crate>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingMoves,
+    MissingLabels,
+    InvalidLabel,
+    CrateOutsideStacks,
+    InvalidMove,
+    InvalidNumber,
+    InvalidStack,
+    UnknownStack,
+    EmptyStack,
+    NotEnoughCrates,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingMoves => write!(f, "expected a blank line followed by the moves"),
+            ErrorKind::MissingLabels => {
+                write!(f, "expected a row of stack numbers below the crates")
+            }
+            ErrorKind::InvalidLabel => write!(f, "expected the stacks to be numbered 1, 2, 3…"),
+            ErrorKind::CrateOutsideStacks => write!(f, "crate that isn't above a stack number"),
+            ErrorKind::InvalidMove => write!(f, "expected a move like `move 1 from 2 to 3`"),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::InvalidStack => write!(f, "stacks are counted from 1"),
+            ErrorKind::UnknownStack => write!(f, "there is no such stack"),
+            ErrorKind::EmptyStack => write!(f, "the stack is empty"),
+            ErrorKind::NotEnoughCrates => write!(f, "the stack doesn't have that many crates"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// Parses a move and, given the height of every stack, checks the move
+/// against them and updates them.
+fn parse_move(
+    number: usize,
+    line: &str,
+    heights: Option<&mut [usize]>,
+) -> Result<Move, ParseError> {
+    let words: Vec<&str> = line.split(' ').collect();
+
+    let ["move", quantity, "from", source, "to", target] = words[..] else {
+        return Err(ParseError::line(ErrorKind::InvalidMove, number, line));
+    };
+
+    let stack = |word: &str| -> Result<usize, ParseError> {
+        let stack: usize = aoc_common::parse_at(word, ErrorKind::InvalidNumber, number, line)?;
+        stack
+            .checked_sub(1)
+            .ok_or_else(|| ParseError::at(ErrorKind::InvalidStack, number, line, word))
+    };
+
+    let mv = Move {
+        quantity: aoc_common::parse_at(quantity, ErrorKind::InvalidNumber, number, line)?,
+        source: stack(source)?,
+        target: stack(target)?,
+    };
+
+    let Some(heights) = heights else {
+        return Ok(mv);
+    };
+
+    validate::take(heights, mv.quantity, mv.source, mv.target).map_err(|problem| {
+        let (kind, word) = match problem {
+            Problem::UnknownStack(stack) if stack == mv.source => (ErrorKind::UnknownStack, source),
+            Problem::UnknownStack(_) => (ErrorKind::UnknownStack, target),
+            Problem::EmptyStack(_) => (ErrorKind::EmptyStack, source),
+            Problem::NotEnoughCrates { .. } => (ErrorKind::NotEnoughCrates, quantity),
+        };
+
+        ParseError::at(kind, number, line, word)
+    })?;
+
+    Ok(mv)
+}
+
+/// Columns taken by the number of each stack in the row below the crates.
+fn parse_labels(number: usize, line: &str) -> Result<Vec<std::ops::Range<usize>>, ParseError> {
+    if line.contains('[') {
+        return Err(ParseError::line(ErrorKind::MissingLabels, number, line));
+    }
+
+    let mut labels = vec![];
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c == ' ' {
+            continue;
+        }
+
+        let mut end = start + c.len_utf8();
+        while let Some((i, c)) = chars.next_if(|&(_, c)| c != ' ') {
+            end = i + c.len_utf8();
+        }
+
+        let label = &line[start..end];
+        if label.parse() != Ok(labels.len() + 1) {
+            return Err(ParseError::at(ErrorKind::InvalidLabel, number, line, label));
+        }
+
+        // Crates are only one character wide, so columns work as offsets.
+        labels.push(line[..start].chars().count()..line[..end].chars().count());
+    }
+
+    if labels.is_empty() {
+        return Err(ParseError::line(ErrorKind::MissingLabels, number, line));
+    }
+
+    Ok(labels)
+}
+
+/// Parses the stack drawing, as many stacks as are numbered below it, and
+/// the moves, checking that every move only takes crates that are there.
+pub fn parse(input: &str) -> Result<(State, Vec<Move>), ParseError> {
+    parse_with(input, true)
+}
+
+/// Like [`parse`], but leaves checking the moves against the stacks to the
+/// caller, for drawings that aren't the starting state. Run [`validate`] or
+/// [`validate_undo`] on the result before simulating.
+pub fn parse_unchecked(input: &str) -> Result<(State, Vec<Move>), ParseError> {
+    parse_with(input, false)
+}
+
+fn parse_with(input: &str, check: bool) -> Result<(State, Vec<Move>), ParseError> {
+    let mut lines = aoc_common::numbered_lines(input);
+
+    let mut drawing = vec![];
+    let blank = loop {
+        match lines.next() {
+            Some((number, "")) => break number,
+            Some(line) => drawing.push(line),
+            None => return Err(ParseError::end(ErrorKind::MissingMoves, input)),
+        }
+    };
+
+    let Some((number, labels)) = drawing.pop() else {
+        return Err(ParseError::line(ErrorKind::MissingLabels, blank, ""));
+    };
+    let labels = parse_labels(number, labels)?;
+
+    let mut state = vec![VecDeque::new(); labels.len()];
+    for (number, line) in drawing {
+        for (j, c) in line.chars().enumerate() {
+            if c.is_alphabetic() {
+                let Some(index) = labels.iter().position(|label| label.contains(&j)) else {
+                    return Err(ParseError::new(
+                        ErrorKind::CrateOutsideStacks,
+                        number,
+                        j + 1,
+                        c,
+                    ));
+                };
+
+                state[index].push_back(c);
+            }
+        }
+    }
+
+    let mut heights: Vec<usize> = state.iter().map(VecDeque::len).collect();
+    let moves = lines
+        .map(|(number, line)| parse_move(number, line, check.then_some(&mut heights[..])))
+        .collect::<Result<_, _>>()?;
+
+    Ok((state, moves))
+}
+
+/// The stacks drawn like in the input, with the crates above a row of stack
+/// numbers.
+pub fn render(state: &State) -> String {
+    let height = state.iter().map(VecDeque::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+
+    for row in (0..height).rev() {
+        let cells: Vec<String> = state
+            .iter()
+            .map(|stack| match stack.len().checked_sub(row + 1) {
+                Some(i) => format!("[{}]", stack[i]),
+                None => String::from("   "),
+            })
+            .collect();
+        drawing += &cells.join(" ");
+        drawing.push('\n');
+    }
+
+    let labels: Vec<String> = (1..=state.len())
+        .map(|label| format!("{label:^3}"))
+        .collect();
+    drawing += &labels.join(" ");
+    drawing.push('\n');
+
+    drawing
+}
+
+/// The crate on top of every stack, skipping empty stacks.
+pub fn top_crates(state: &State) -> String {
+    state.iter().filter_map(VecDeque::front).collect()
+}
+
+pub fn part1(state: &State, moves: &[Move]) -> String {
+    top_crates(Simulator::new(CrateMover9000, state.clone(), moves).run())
+}
+
+pub fn part2(state: &State, moves: &[Move]) -> String {
+    top_crates(Simulator::new(CrateMover9001, state.clone(), moves).run())
+}
+
+pub struct Day05;
+
+impl aoc_common::Solution for Day05 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = (State, Vec<Move>);
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(&input.0, &input.1))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(&input.0, &input.1))
+    }
+}
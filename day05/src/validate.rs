@@ -0,0 +1,118 @@
+use std::fmt;
+
+use crate::{Move, State};
+
+/// Why a move can't be carried out. Stacks are counted from zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Problem {
+    UnknownStack(usize),
+    /// The stack to take crates from has none.
+    EmptyStack(usize),
+    /// The stack to take crates from has fewer crates than the move takes.
+    NotEnoughCrates {
+        stack: usize,
+        available: usize,
+    },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Problem::UnknownStack(stack) => write!(f, "there is no stack {}", stack + 1),
+            Problem::EmptyStack(stack) => write!(f, "stack {} is empty", stack + 1),
+            Problem::NotEnoughCrates { stack, available } => {
+                write!(f, "stack {} only has {available} crates", stack + 1)
+            }
+        }
+    }
+}
+
+/// The first move of a list that can't be carried out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IllegalMove {
+    /// Index of the move in the list.
+    pub index: usize,
+    pub mv: Move,
+    pub problem: Problem,
+}
+
+impl fmt::Display for IllegalMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` (move {}): {}",
+            self.mv,
+            self.index + 1,
+            self.problem
+        )
+    }
+}
+
+impl std::error::Error for IllegalMove {}
+
+/// Moves `quantity` crates from `from` to `to` in the height of every stack,
+/// if they are there.
+pub(crate) fn take(
+    heights: &mut [usize],
+    quantity: usize,
+    from: usize,
+    to: usize,
+) -> Result<(), Problem> {
+    for stack in [from, to] {
+        if stack >= heights.len() {
+            return Err(Problem::UnknownStack(stack));
+        }
+    }
+
+    match heights[from] {
+        available if available >= quantity => {}
+        0 => return Err(Problem::EmptyStack(from)),
+        available => {
+            return Err(Problem::NotEnoughCrates {
+                stack: from,
+                available,
+            })
+        }
+    }
+
+    heights[from] -= quantity;
+    heights[to] += quantity;
+
+    Ok(())
+}
+
+fn heights(state: &State) -> Vec<usize> {
+    state.iter().map(|stack| stack.len()).collect()
+}
+
+/// Checks that every move only takes crates that are there when it comes,
+/// starting from `state`. Which crane carries out the moves doesn't matter.
+pub fn validate(state: &State, moves: &[Move]) -> Result<(), IllegalMove> {
+    let mut heights = heights(state);
+
+    for (index, mv) in moves.iter().enumerate() {
+        take(&mut heights, mv.quantity, mv.source, mv.target).map_err(|problem| IllegalMove {
+            index,
+            mv: *mv,
+            problem,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the moves can be undone, last one first, starting from
+/// `state` as it is after all of them.
+pub fn validate_undo(state: &State, moves: &[Move]) -> Result<(), IllegalMove> {
+    let mut heights = heights(state);
+
+    for (index, mv) in moves.iter().enumerate().rev() {
+        take(&mut heights, mv.quantity, mv.target, mv.source).map_err(|problem| IllegalMove {
+            index,
+            mv: *mv,
+            problem,
+        })?;
+    }
+
+    Ok(())
+}
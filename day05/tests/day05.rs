@@ -0,0 +1,226 @@
+use aoc_common::{answer, Part};
+use day05::{
+    Crane, CrateMover9000, CrateMover9001, Day05, ErrorKind, IllegalMove, Move, Problem, Simulator,
+    State, UpTo,
+};
+
+const EXAMPLE: &str = include_str!("../input_test");
+/// The example's stacks after all its moves with the CrateMover 9000.
+const EXAMPLE_END: &str = include_str!("../input_test_end");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day05>(EXAMPLE, Part::One), "CMZ");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day05>(EXAMPLE, Part::Two), "MCD");
+}
+
+#[test]
+fn more_than_nine_stacks() {
+    let input = "\
+[A] [B] [C] [D] [E] [F] [G] [H] [I] [J] [K]
+ 1   2   3   4   5   6   7   8   9  10  11
+
+move 1 from 11 to 1
+";
+
+    assert_eq!(answer::<Day05>(input, Part::One), "KBCDEFGHIJ");
+}
+
+#[test]
+fn invalid_drawings_and_moves() {
+    let error = |input: &str| {
+        let error = day05::parse(input).err().unwrap();
+        (error.kind, error.line)
+    };
+
+    assert_eq!(
+        error("[Z] [M]\n 1   2\n\nmove 1 from 2 to 3"),
+        (ErrorKind::UnknownStack, 4)
+    );
+    assert_eq!(
+        error("[Z] [M]\n 1   2\n\nmove 1 from 2 to 1\nmove 1 from 2 to 1"),
+        (ErrorKind::EmptyStack, 5)
+    );
+    assert_eq!(
+        error("[Z] [M]\n 1   2\n\nmove 2 from 2 to 1"),
+        (ErrorKind::NotEnoughCrates, 4)
+    );
+    assert_eq!(
+        error("[Z] [M] [P]\n 1   2\n\nmove 1 from 2 to 1"),
+        (ErrorKind::CrateOutsideStacks, 1)
+    );
+    assert_eq!(
+        error("[Z] [M]\n 2   1\n\nmove 1 from 2 to 1"),
+        (ErrorKind::InvalidLabel, 2)
+    );
+    assert_eq!(
+        error("[Z] [M]\n\nmove 1 from 2 to 1"),
+        (ErrorKind::MissingLabels, 1)
+    );
+}
+
+#[test]
+fn render_like_the_input() {
+    let (state, _) = day05::parse(EXAMPLE).unwrap();
+    let drawing = EXAMPLE.split("\n\n").next().unwrap();
+
+    assert_eq!(day05::render(&state), format!("{drawing}\n"));
+}
+
+#[test]
+fn step_through_moves() {
+    let (state, moves) = day05::parse(EXAMPLE).unwrap();
+    let mut simulator = Simulator::new(CrateMover9001, state, &moves);
+
+    assert_eq!(
+        simulator.step(),
+        Some(&Move {
+            quantity: 1,
+            source: 1,
+            target: 0
+        })
+    );
+    assert_eq!(simulator.step().unwrap().to_string(), "move 3 from 1 to 3");
+    assert_eq!(
+        day05::render(simulator.state()),
+        "        [D]\n        [N]\n    [C] [Z]\n    [M] [P]\n 1   2   3 \n"
+    );
+    assert_eq!(simulator.done(), 2);
+
+    simulator.run();
+    assert!(simulator.is_done());
+    assert_eq!(simulator.step(), None);
+    assert_eq!(day05::top_crates(simulator.state()), "MCD");
+}
+
+#[test]
+fn crane_lifting_two_at_once() {
+    let (state, moves) = day05::parse(EXAMPLE).unwrap();
+    let mut simulator = Simulator::new(UpTo(2), state, &moves);
+
+    assert_eq!(day05::top_crates(simulator.run()), "MCZ");
+}
+
+#[test]
+fn first_illegal_move() {
+    let (state, mut moves) = day05::parse(EXAMPLE).unwrap();
+    assert_eq!(day05::validate(&state, &moves), Ok(()));
+
+    let mv = |quantity, source, target| Move {
+        quantity,
+        source,
+        target,
+    };
+    moves.insert(2, mv(1, 0, 1));
+    moves.push(mv(9, 1, 0));
+    moves.push(mv(1, 0, 3));
+
+    let error = day05::validate(&state, &moves).unwrap_err();
+    assert_eq!(
+        error,
+        IllegalMove {
+            index: 2,
+            mv: mv(1, 0, 1),
+            problem: Problem::EmptyStack(0),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "`move 1 from 1 to 2` (move 3): stack 1 is empty"
+    );
+
+    moves.remove(2);
+    assert_eq!(
+        day05::validate(&state, &moves).unwrap_err().problem,
+        Problem::NotEnoughCrates {
+            stack: 1,
+            available: 1
+        }
+    );
+    assert_eq!(
+        day05::validate(&state, &moves[..4]).map_err(|error| error.problem),
+        Ok(())
+    );
+    assert_eq!(
+        day05::validate(&state, &[mv(1, 0, 3)]).unwrap_err().problem,
+        Problem::UnknownStack(3)
+    );
+}
+
+#[test]
+fn undo_back_to_the_start() {
+    let (state, moves) = day05::parse(INPUT).unwrap();
+
+    let mut forward = Simulator::new(UpTo(3), state.clone(), &moves);
+    let end = forward.run().clone();
+    assert_eq!(day05::validate_undo(&end, &moves), Ok(()));
+
+    let mut backward = Simulator::finished(UpTo(3), end, &moves);
+    assert_eq!(backward.undo(), moves.last());
+    assert_eq!(backward.rewind(), &state);
+    assert_eq!(backward.done(), 0);
+
+    fn round_trip(crane: impl Crane + Copy, state: &State, moves: &[Move]) -> State {
+        let end = Simulator::new(crane, state.clone(), moves).run().clone();
+
+        Simulator::finished(crane, end, moves).rewind().clone()
+    }
+    assert_eq!(round_trip(CrateMover9000, &state, &moves), state);
+    assert_eq!(round_trip(CrateMover9001, &state, &moves), state);
+
+    let (start, _) = day05::parse(EXAMPLE).unwrap();
+    assert_eq!(
+        day05::validate_undo(&start, &moves[..1]).map_err(|error| error.index),
+        Err(0)
+    );
+}
+
+#[test]
+fn undo_from_an_end_state() {
+    assert_eq!(
+        day05::parse(EXAMPLE_END).map_err(|error| (error.kind, error.line)),
+        Err((ErrorKind::NotEnoughCrates, 8))
+    );
+
+    let (end, moves) = day05::parse_unchecked(EXAMPLE_END).unwrap();
+    assert_eq!(day05::validate_undo(&end, &moves), Ok(()));
+
+    let (start, _) = day05::parse(EXAMPLE).unwrap();
+    assert_eq!(
+        Simulator::finished(CrateMover9000, end, &moves).rewind(),
+        &start
+    );
+}
+
+#[test]
+fn stacks_undo() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_stacks"))
+        .args([
+            "--undo",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input_test_end"),
+        ])
+        .output()
+        .unwrap();
+    let drawing = EXAMPLE.split("\n\n").next().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("{drawing}\n\ntop: NDP\n")
+    );
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day05>(INPUT, Part::One), "SVFDLGLWV");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day05>(INPUT, Part::Two), "DCVTCVPCL");
+}
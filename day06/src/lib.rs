@@ -0,0 +1,78 @@
+use std::fmt;
+
+/// Distinct characters in a start-of-packet marker.
+pub const PACKET_MARKER: usize = 4;
+/// Distinct characters in a start-of-message marker.
+pub const MESSAGE_MARKER: usize = 14;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// No marker of this many distinct characters.
+    NoMarker(usize),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::NoMarker(distinct) => {
+                write!(f, "no {distinct} characters in a row are all different")
+            }
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// Takes the signal as it is. Each part looks for its own marker, so a
+/// signal too short for a start-of-message marker still has an answer to
+/// part 1.
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    Ok(input.to_string())
+}
+
+/// Points at the end of the signal, for when a part finds no marker.
+fn no_marker(input: &str, distinct: usize) -> ParseError {
+    ParseError::end(ErrorKind::NoMarker(distinct), input)
+}
+
+/// Position just after the first `distinct` characters that are all different.
+pub fn find_start_of_message_marker(input: &str, distinct: usize) -> Option<usize> {
+    input
+        .chars()
+        .collect::<Vec<char>>()
+        .windows(distinct)
+        .position(|window| window.iter().collect::<std::collections::HashSet<&char>>().len() == distinct)
+        .map(|position| position + distinct)
+}
+
+/// Position after the start-of-packet marker, if there is one.
+pub fn part1(input: &str) -> Option<usize> {
+    find_start_of_message_marker(input, PACKET_MARKER)
+}
+
+/// Position after the start-of-message marker, if there is one.
+pub fn part2(input: &str) -> Option<usize> {
+    find_start_of_message_marker(input, MESSAGE_MARKER)
+}
+
+pub struct Day06;
+
+impl aoc_common::Solution for Day06 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = String;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        part1(input).ok_or_else(|| no_marker(input, PACKET_MARKER))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        part2(input).ok_or_else(|| no_marker(input, MESSAGE_MARKER))
+    }
+}
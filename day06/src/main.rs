@@ -1,29 +1,3 @@
-fn read_input() -> String {
-    let input = include_str!("../input");
-    input.to_string()
-}
-
-fn find_start_of_message_marker(input: &str, distinct: usize) -> usize {
-    input
-        .chars()
-        .collect::<Vec<char>>()
-        .windows(distinct)
-        .position(|window| window.iter().collect::<std::collections::HashSet<&char>>().len() == distinct)
-        .unwrap()
-        + distinct
-}
-
-fn part1(input: &str) -> usize {
-    find_start_of_message_marker(input, 4)
-}
-
-fn part2(input: &str) -> usize {
-    find_start_of_message_marker(input, 14)
-}
-
-fn main() {
-    let input = read_input();
-
-    println!("part1: {}", part1(&input));
-    println!("part2: {}", part2(&input));
+fn main() -> std::process::ExitCode {
+    aoc_common::main::<day06::Day06>()
 }
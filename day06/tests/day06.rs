@@ -0,0 +1,40 @@
+use aoc_common::{answer, Part};
+use day06::Day06;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day06>(EXAMPLE, Part::One), "7");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day06>(EXAMPLE, Part::Two), "19");
+}
+
+#[test]
+fn no_marker() {
+    assert_eq!(day06::part1("abc"), None);
+    assert_eq!(day06::part1("aaaabcd"), Some(7));
+    assert_eq!(day06::part2("aaaabcd"), None);
+
+    // A stream too short for part 2 still answers part 1.
+    assert_eq!(answer::<Day06>("aaaabcd", Part::One), "7");
+    let err = aoc_common::solve::<Day06>("aaaabcd", &[Part::Two], false, &mut |_| {}).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid input: line 2, column 1: no 14 characters in a row are all different"
+    );
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day06>(INPUT, Part::One), "1531");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day06>(INPUT, Part::Two), "2518");
+}
@@ -0,0 +1,110 @@
+//! Shows the filesystem explored by day 07's terminal transcript, and what to
+//! delete to free space on it.
+
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::{Error, Result, Solution, Source};
+use day07::{Day07, DirId, Disk, FileSystem};
+
+const USAGE: &str = "\
+filesystem <tree | json | script> [INPUT | -]
+       filesystem du [N] [INPUT | -]
+       filesystem <below | above> <SIZE> [INPUT | -]
+       filesystem clean [--capacity <SIZE>] [--needed <SIZE>] [INPUT | -]";
+
+enum View {
+    Tree,
+    Json,
+    Script,
+    /// The given number of largest directories.
+    Du(usize),
+    /// Directories of at most the given size.
+    Below(usize),
+    /// Directories larger than the given size.
+    Above(usize),
+    /// What to delete to free enough space.
+    Clean(Disk),
+}
+
+fn size(value: Option<String>, what: &str) -> Result<usize> {
+    let value = value.ok_or_else(|| Error::Argument(format!("missing {what}")))?;
+
+    value
+        .parse()
+        .map_err(|_| Error::Argument(format!("invalid {what} `{value}`")))
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<(View, Source)> {
+    let usage = || Error::Usage(USAGE.to_string());
+    let mut args = args.peekable();
+
+    let mut view = match args.next().as_deref() {
+        Some("tree") => View::Tree,
+        Some("json") => View::Json,
+        Some("script") => View::Script,
+        Some("du") => match args.next_if(|arg| arg.parse::<usize>().is_ok()) {
+            Some(count) => View::Du(count.parse().unwrap()),
+            None => View::Du(10),
+        },
+        Some("below") => View::Below(size(args.next(), "size")?),
+        Some("above") => View::Above(size(args.next(), "size")?),
+        Some("clean") => View::Clean(Disk::default()),
+        _ => return Err(usage()),
+    };
+
+    let mut source = Source::File(PathBuf::from(Day07::INPUT));
+    while let Some(arg) = args.next() {
+        match (&mut view, arg.as_str()) {
+            (View::Clean(disk), "--capacity") => disk.capacity = size(args.next(), "capacity")?,
+            (View::Clean(disk), "--needed") => disk.needed = size(args.next(), "needed space")?,
+            (_, input) if !input.starts_with("--") && args.peek().is_none() => {
+                source = Source::from_arg(input);
+            }
+            _ => return Err(usage()),
+        }
+    }
+
+    Ok((view, source))
+}
+
+fn print_dirs(fs: &FileSystem, dirs: &[DirId]) {
+    for &id in dirs {
+        println!("{}\t{}", fs.dir(id).size(), fs.path(id));
+    }
+}
+
+fn main() -> ExitCode {
+    aoc_common::run(|| {
+        let (view, source) = parse_args(std::env::args().skip(1))?;
+        let fs = Day07::parse(&source.read()?).map_err(|err| Error::Parse(Box::new(err)))?;
+
+        match view {
+            View::Tree => print!("{}", fs.tree()),
+            View::Json => println!("{:#}", fs.to_json()),
+            View::Script => print!("{}", fs.script()),
+            View::Du(count) => print!("{}", fs.du(count)),
+            View::Below(size) => print_dirs(&fs, &day07::dirs_at_most(&fs, size)),
+            View::Above(size) => print_dirs(&fs, &day07::dirs_above(&fs, size)),
+            View::Clean(disk) => {
+                println!("to free: {}", disk.to_free(&fs));
+                match day07::smallest_to_delete(&fs, disk) {
+                    Some(id) => println!(
+                        "smallest single directory:\n{}\t{}",
+                        fs.dir(id).size(),
+                        fs.path(id)
+                    ),
+                    None => println!("no single directory frees enough"),
+                }
+                match day07::cheapest_deletion(&fs, disk) {
+                    Some(dirs) => {
+                        println!("least deleted:");
+                        print_dirs(&fs, &dirs);
+                    }
+                    None => println!("deleting everything doesn't free enough"),
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
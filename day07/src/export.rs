@@ -0,0 +1,95 @@
+use std::fmt::Write;
+
+use serde_json::{json, Value};
+
+use crate::{DirId, Entry, FileSystem};
+
+/// Quotes `text` for a POSIX shell.
+fn quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', r"'\''"))
+}
+
+impl FileSystem {
+    /// The tree drawn like the `tree` command, with the size of every entry
+    /// after its name.
+    pub fn tree(&self) -> String {
+        let mut tree = format!("/ ({})\n", self.root().size());
+        self.draw(FileSystem::ROOT, "", &mut tree);
+
+        tree
+    }
+
+    fn draw(&self, id: DirId, indent: &str, tree: &mut String) {
+        let entries = self.dir(id).entries();
+
+        for (i, (name, entry)) in entries.iter().enumerate() {
+            let last = i + 1 == entries.len();
+            let (branch, more) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+
+            match *entry {
+                Entry::Dir(child) => {
+                    let size = self.dir(child).size();
+                    writeln!(tree, "{indent}{branch}{name} ({size})").unwrap();
+                    self.draw(child, &format!("{indent}{more}"), tree);
+                }
+                Entry::File(size) => writeln!(tree, "{indent}{branch}{name} ({size})").unwrap(),
+            }
+        }
+    }
+
+    /// The tree in the shape `tree -J` uses, with the size of every entry.
+    pub fn to_json(&self) -> Value {
+        self.dir_json(FileSystem::ROOT, "/")
+    }
+
+    fn dir_json(&self, id: DirId, name: &str) -> Value {
+        let dir = self.dir(id);
+        let contents: Vec<Value> = dir
+            .entries()
+            .into_iter()
+            .map(|(name, entry)| match entry {
+                Entry::Dir(child) => self.dir_json(child, name),
+                Entry::File(size) => json!({"type": "file", "name": name, "size": size}),
+            })
+            .collect();
+
+        json!({"type": "directory", "name": name, "size": dir.size(), "contents": contents})
+    }
+
+    /// A shell script recreating the tree in a new temporary directory, with
+    /// sparse files of the right sizes so that it takes no space. Prints the
+    /// directory it made.
+    pub fn script(&self) -> String {
+        let mut script = String::from(concat!(
+            "#!/bin/sh\n",
+            "set -e\n",
+            "root=$(mktemp -d)\n",
+            "cd \"$root\"\n",
+        ));
+
+        for id in self.walk() {
+            let path = self.path(id);
+            let dir = path.trim_start_matches('/');
+            if !dir.is_empty() {
+                writeln!(script, "mkdir -- {}", quote(dir)).unwrap();
+            }
+
+            for (name, size) in self.dir(id).files() {
+                let file = if dir.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{dir}/{name}")
+                };
+                writeln!(script, "truncate -s {size} -- {}", quote(&file)).unwrap();
+            }
+        }
+
+        script.push_str("echo \"$root\"\n");
+
+        script
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+
+/// Index of a directory in its [`FileSystem`].
+pub type DirId = usize;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dir {
+    name: String,
+    parent: Option<DirId>,
+    dirs: BTreeMap<String, DirId>,
+    files: BTreeMap<String, usize>,
+    /// Total size of everything inside, filled in once the tree is complete.
+    size: usize,
+}
+
+impl Dir {
+    /// Name of the directory, empty for the root.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn parent(&self) -> Option<DirId> {
+        self.parent
+    }
+
+    /// Subdirectories by name.
+    pub fn dirs(&self) -> impl Iterator<Item = (&str, DirId)> {
+        self.dirs.iter().map(|(name, &id)| (name.as_str(), id))
+    }
+
+    /// Files by name, with their sizes.
+    pub fn files(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.files.iter().map(|(name, &size)| (name.as_str(), size))
+    }
+
+    /// Subdirectories and files together, by name.
+    pub fn entries(&self) -> Vec<(&str, Entry)> {
+        let mut entries: Vec<(&str, Entry)> = self
+            .dirs()
+            .map(|(name, id)| (name, Entry::Dir(id)))
+            .chain(self.files().map(|(name, size)| (name, Entry::File(size))))
+            .collect();
+        entries.sort_by_key(|&(name, _)| name);
+
+        entries
+    }
+
+    /// Total size of everything inside this directory, recursively.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// What a path leads to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Entry {
+    Dir(DirId),
+    File(usize),
+}
+
+/// Directories and files seen in a terminal transcript. Listing a directory
+/// twice doesn't count its files twice.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileSystem {
+    /// Directories in the order they were first seen, so every directory
+    /// comes after its parent. The root comes first.
+    dirs: Vec<Dir>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        FileSystem {
+            dirs: vec![Dir {
+                name: String::new(),
+                parent: None,
+                dirs: BTreeMap::new(),
+                files: BTreeMap::new(),
+                size: 0,
+            }],
+        }
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: DirId = 0;
+
+    pub fn dir(&self, id: DirId) -> &Dir {
+        &self.dirs[id]
+    }
+
+    pub fn root(&self) -> &Dir {
+        self.dir(FileSystem::ROOT)
+    }
+
+    /// Subdirectory `name` of `parent`, added if it isn't known yet.
+    pub(crate) fn add_dir(&mut self, parent: DirId, name: &str) -> DirId {
+        if let Some(&id) = self.dirs[parent].dirs.get(name) {
+            return id;
+        }
+
+        let id = self.dirs.len();
+        self.dirs.push(Dir {
+            name: name.to_string(),
+            parent: Some(parent),
+            dirs: BTreeMap::new(),
+            files: BTreeMap::new(),
+            size: 0,
+        });
+        self.dirs[parent].dirs.insert(name.to_string(), id);
+
+        id
+    }
+
+    pub(crate) fn add_file(&mut self, parent: DirId, name: &str, size: usize) {
+        self.dirs[parent].files.insert(name.to_string(), size);
+    }
+
+    /// Works out the size of every directory, once all of them are added.
+    pub(crate) fn update_sizes(&mut self) {
+        // Children come after their parents, so going backwards every
+        // directory is complete before it is added to its parent.
+        for id in (0..self.dirs.len()).rev() {
+            let dir = &mut self.dirs[id];
+            dir.size += dir.files.values().sum::<usize>();
+
+            if let Some(parent) = dir.parent {
+                let size = dir.size;
+                self.dirs[parent].size += size;
+            }
+        }
+    }
+
+    /// Absolute path of a directory, like `/a/e`.
+    pub fn path(&self, id: DirId) -> String {
+        let mut names = vec![];
+        let mut dir = self.dir(id);
+        while let Some(parent) = dir.parent {
+            names.push(dir.name.as_str());
+            dir = self.dir(parent);
+        }
+        names.reverse();
+
+        format!("/{}", names.join("/"))
+    }
+
+    /// What an absolute path like `/a/e` or `/b.txt` leads to, if anything.
+    pub fn lookup(&self, path: &str) -> Option<Entry> {
+        let mut entry = Entry::Dir(FileSystem::ROOT);
+
+        for name in path
+            .strip_prefix('/')?
+            .split('/')
+            .filter(|name| !name.is_empty())
+        {
+            let Entry::Dir(id) = entry else {
+                return None;
+            };
+            let dir = self.dir(id);
+
+            entry = match (dir.dirs.get(name), dir.files.get(name)) {
+                (Some(&id), _) => Entry::Dir(id),
+                (None, Some(&size)) => Entry::File(size),
+                (None, None) => return None,
+            };
+        }
+
+        Some(entry)
+    }
+
+    /// Every directory, depth first and by name, starting with the root.
+    pub fn walk(&self) -> impl Iterator<Item = DirId> + '_ {
+        let mut stack = vec![FileSystem::ROOT];
+
+        std::iter::from_fn(move || {
+            let id = stack.pop()?;
+            stack.extend(self.dir(id).dirs.values().rev());
+
+            Some(id)
+        })
+    }
+
+    /// Every directory, in no particular order.
+    pub fn dirs(&self) -> impl Iterator<Item = &Dir> {
+        self.dirs.iter()
+    }
+
+    /// The `count` largest directories, largest first, one `<size>\t<path>`
+    /// line each like `du`.
+    pub fn du(&self, count: usize) -> String {
+        let mut ids: Vec<DirId> = self.walk().collect();
+        ids.sort_by_key(|&id| std::cmp::Reverse(self.dir(id).size));
+
+        ids.iter()
+            .take(count)
+            .map(|&id| format!("{}\t{}\n", self.dir(id).size, self.path(id)))
+            .collect()
+    }
+}
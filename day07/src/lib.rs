@@ -0,0 +1,295 @@
+use std::{collections::HashSet, fmt};
+
+mod export;
+mod fs;
+
+pub use fs::{Dir, DirId, Entry, FileSystem};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownCommand,
+    OutputWithoutLs,
+    InvalidListing,
+    InvalidSize,
+    AboveRoot,
+    OutsideRoot,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownCommand => write!(f, "unknown command, expected `cd` or `ls`"),
+            ErrorKind::OutputWithoutLs => write!(f, "output that doesn't follow `ls`"),
+            ErrorKind::InvalidListing => write!(f, "expected `dir <name>` or `<size> <name>`"),
+            ErrorKind::InvalidSize => write!(f, "invalid file size"),
+            ErrorKind::AboveRoot => write!(f, "`cd ..` out of the outermost directory"),
+            ErrorKind::OutsideRoot => write!(f, "`ls` or `cd <name>` before `cd /`"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// Builds the tree of directories and files the transcript explores.
+pub fn parse(input: &str) -> Result<FileSystem, ParseError> {
+    let mut lines = aoc_common::numbered_lines(input).peekable();
+    let mut fs = FileSystem::default();
+    // Nothing is known about where the transcript starts until `cd /`.
+    let mut current = None;
+
+    while let Some((number, line)) = lines.next() {
+        let Some(command) = line.strip_prefix("$ ") else {
+            return Err(ParseError::line(ErrorKind::OutputWithoutLs, number, line));
+        };
+
+        match command.split_once(' ') {
+            Some(("cd", "/")) => current = Some(FileSystem::ROOT),
+            Some(("cd", "..")) => {
+                current = match current.and_then(|id| fs.dir(id).parent()) {
+                    Some(parent) => Some(parent),
+                    None => return Err(ParseError::line(ErrorKind::AboveRoot, number, line)),
+                };
+            }
+            Some(("cd", name)) => {
+                let Some(id) = current else {
+                    return Err(ParseError::line(ErrorKind::OutsideRoot, number, line));
+                };
+
+                current = Some(fs.add_dir(id, name));
+            }
+            None if command == "ls" => {
+                let Some(id) = current else {
+                    return Err(ParseError::line(ErrorKind::OutsideRoot, number, line));
+                };
+
+                while let Some((number, ls_line)) =
+                    lines.next_if(|(_, line)| !line.starts_with('$'))
+                {
+                    match ls_line.split_once(' ') {
+                        Some(("dir", name)) => {
+                            fs.add_dir(id, name);
+                        }
+                        Some((size, name)) => {
+                            let size = aoc_common::parse_at(
+                                size,
+                                ErrorKind::InvalidSize,
+                                number,
+                                ls_line,
+                            )?;
+                            fs.add_file(id, name, size);
+                        }
+                        None => {
+                            return Err(ParseError::line(
+                                ErrorKind::InvalidListing,
+                                number,
+                                ls_line,
+                            ))
+                        }
+                    }
+                }
+            }
+            _ => {
+                return Err(ParseError::at(
+                    ErrorKind::UnknownCommand,
+                    number,
+                    line,
+                    command,
+                ))
+            }
+        }
+    }
+
+    fs.update_sizes();
+
+    Ok(fs)
+}
+
+/// Directories count as small up to this size in part 1.
+pub const SMALL_DIR: usize = 100_000;
+
+/// The disk the filesystem is on, and how much of it needs to be free.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Disk {
+    pub capacity: usize,
+    pub needed: usize,
+}
+
+impl Default for Disk {
+    fn default() -> Self {
+        Disk {
+            capacity: 70_000_000,
+            needed: 30_000_000,
+        }
+    }
+}
+
+impl Disk {
+    /// How much has to be deleted to have the needed space free.
+    pub fn to_free(&self, fs: &FileSystem) -> usize {
+        let free = self.capacity.saturating_sub(fs.root().size());
+
+        self.needed.saturating_sub(free)
+    }
+}
+
+/// Directories of at most `size`, depth first.
+pub fn dirs_at_most(fs: &FileSystem, size: usize) -> Vec<DirId> {
+    fs.walk().filter(|&id| fs.dir(id).size() <= size).collect()
+}
+
+/// Directories larger than `size`, depth first.
+pub fn dirs_above(fs: &FileSystem, size: usize) -> Vec<DirId> {
+    fs.walk().filter(|&id| fs.dir(id).size() > size).collect()
+}
+
+/// The smallest directory that frees enough space on its own, if there is
+/// one and anything needs freeing at all.
+pub fn smallest_to_delete(fs: &FileSystem, disk: Disk) -> Option<DirId> {
+    let to_free = disk.to_free(fs);
+    if to_free == 0 {
+        return None;
+    }
+
+    fs.walk()
+        .filter(|&id| fs.dir(id).size() >= to_free)
+        .min_by_key(|&id| fs.dir(id).size())
+}
+
+/// Depth-first search for [`cheapest_deletion`] over the directories in
+/// depth-first order, each one either kept or deleted with everything inside.
+struct Cleanup {
+    sizes: Vec<usize>,
+    /// Position after everything inside the directory at each position.
+    after: Vec<usize>,
+    /// Most that can be deleted from each position on, which is everything
+    /// outside the directories before it.
+    most: Vec<usize>,
+    to_free: usize,
+    /// Positions and total size already searched from, which can't do
+    /// better the second time.
+    seen: HashSet<(usize, usize)>,
+    deleting: Vec<usize>,
+    best: Option<(usize, Vec<usize>)>,
+}
+
+impl Cleanup {
+    fn search(&mut self, i: usize, deleted: usize) {
+        if i == self.sizes.len()
+            || deleted + self.most[i] < self.to_free
+            || !self.seen.insert((i, deleted))
+        {
+            return;
+        }
+        // Nothing deletes less than exactly what is needed.
+        if self
+            .best
+            .as_ref()
+            .is_some_and(|(best, _)| *best == self.to_free)
+        {
+            return;
+        }
+
+        let size = self.sizes[i];
+        self.deleting.push(i);
+        if deleted + size >= self.to_free {
+            if self
+                .best
+                .as_ref()
+                .is_none_or(|(best, _)| deleted + size < *best)
+            {
+                self.best = Some((deleted + size, self.deleting.clone()));
+            }
+        } else {
+            self.search(self.after[i], deleted + size);
+        }
+        self.deleting.pop();
+
+        self.search(i + 1, deleted);
+    }
+}
+
+/// Directories that together free enough space while deleting as little as
+/// possible, which can beat deleting the [smallest single
+/// one](smallest_to_delete).
+pub fn cheapest_deletion(fs: &FileSystem, disk: Disk) -> Option<Vec<DirId>> {
+    let to_free = disk.to_free(fs);
+    if to_free == 0 {
+        return Some(vec![]);
+    }
+
+    let order: Vec<DirId> = fs.walk().collect();
+    let mut position = vec![0; order.len()];
+    for (i, &id) in order.iter().enumerate() {
+        position[id] = i;
+    }
+    let mut after = vec![0; order.len()];
+    for (i, &id) in order.iter().enumerate().rev() {
+        after[i] = fs
+            .dir(id)
+            .dirs()
+            .map(|(_, child)| after[position[child]])
+            .max()
+            .unwrap_or(i + 1);
+    }
+
+    let sizes: Vec<usize> = order.iter().map(|&id| fs.dir(id).size()).collect();
+    // A directory is at least as large as anything deleted inside it.
+    let mut most = vec![0; order.len() + 1];
+    for i in (0..order.len()).rev() {
+        most[i] = sizes[i] + most[after[i]];
+    }
+
+    let mut cleanup = Cleanup {
+        sizes,
+        after,
+        most,
+        to_free,
+        seen: HashSet::new(),
+        deleting: vec![],
+        best: None,
+    };
+    cleanup.search(0, 0);
+
+    let (_, deleting) = cleanup.best?;
+    Some(deleting.into_iter().map(|i| order[i]).collect())
+}
+
+/// Total size of the directories of at most `limit`, counting nested ones
+/// again.
+pub fn total_small(fs: &FileSystem, limit: usize) -> usize {
+    dirs_at_most(fs, limit)
+        .iter()
+        .map(|&id| fs.dir(id).size())
+        .sum()
+}
+
+pub fn part1(fs: &FileSystem) -> usize {
+    total_small(fs, SMALL_DIR)
+}
+
+/// Size of the smallest directory to delete, or 0 if there is none.
+pub fn part2(fs: &FileSystem) -> usize {
+    smallest_to_delete(fs, Disk::default()).map_or(0, |id| fs.dir(id).size())
+}
+
+pub struct Day07;
+
+impl aoc_common::Solution for Day07 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = FileSystem;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
@@ -0,0 +1,152 @@
+use aoc_common::{answer, Part};
+use day07::{Day07, Disk, Entry};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day07>(EXAMPLE, Part::One), "95437");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day07>(EXAMPLE, Part::Two), "24933642");
+}
+
+#[test]
+fn tree() {
+    let fs = day07::parse(EXAMPLE).unwrap();
+
+    assert_eq!(fs.root().size(), 48381165);
+    assert_eq!(fs.lookup("/b.txt"), Some(Entry::File(14848514)));
+    assert_eq!(fs.lookup("/a/e/x"), None);
+    let Some(Entry::Dir(e)) = fs.lookup("/a/e/") else {
+        panic!("/a/e should be a directory");
+    };
+    assert_eq!((fs.dir(e).name(), fs.dir(e).size()), ("e", 584));
+    assert_eq!(fs.path(e), "/a/e");
+
+    let paths: Vec<String> = fs.walk().map(|id| fs.path(id)).collect();
+    assert_eq!(paths, ["/", "/a", "/a/e", "/d"]);
+
+    assert_eq!(fs.du(2), "48381165\t/\n24933642\t/d\n");
+}
+
+#[test]
+fn repeated_ls_and_cd_root() {
+    let fs =
+        day07::parse("$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 c\n$ cd /\n$ ls\n10 b\ndir a")
+            .unwrap();
+
+    assert_eq!(fs.root().size(), 15);
+    assert_eq!(fs.dirs().count(), 2);
+    assert_eq!(fs.root().parent(), None);
+    assert_eq!(fs.lookup("/a/c"), Some(Entry::File(5)));
+}
+
+#[test]
+fn exports() {
+    let fs = day07::parse("$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 it's").unwrap();
+
+    assert_eq!(
+        fs.tree(),
+        "/ (15)\n├── a (5)\n│   └── it's (5)\n└── b (10)\n"
+    );
+    assert_eq!(
+        fs.to_json().to_string(),
+        concat!(
+            r#"{"type":"directory","name":"/","size":15,"contents":["#,
+            r#"{"type":"directory","name":"a","size":5,"contents":["#,
+            r#"{"type":"file","name":"it's","size":5}]},"#,
+            r#"{"type":"file","name":"b","size":10}]}"#,
+        )
+    );
+
+    let script = fs.script();
+    assert!(script.contains("truncate -s 10 -- 'b'\nmkdir -- 'a'\n"));
+    assert!(script.contains(r"truncate -s 5 -- 'a/it'\''s'"));
+}
+
+#[test]
+fn cleanup() {
+    let fs = day07::parse(concat!(
+        "$ cd /\n$ ls\ndir a\ndir b\n$ cd a\n$ ls\n40 f\n$ cd ..\n",
+        "$ cd b\n$ ls\n30 g\ndir c\n$ cd c\n$ ls\n25 h",
+    ))
+    .unwrap();
+    let paths = |dirs: &[usize]| dirs.iter().map(|&id| fs.path(id)).collect::<Vec<_>>();
+    let disk = Disk {
+        capacity: 100,
+        needed: 65,
+    };
+
+    assert_eq!(paths(&day07::dirs_at_most(&fs, 40)), ["/a", "/b/c"]);
+    assert_eq!(paths(&day07::dirs_above(&fs, 40)), ["/", "/b"]);
+    assert_eq!(day07::total_small(&fs, 40), 65);
+
+    assert_eq!(disk.to_free(&fs), 60);
+    assert_eq!(
+        day07::smallest_to_delete(&fs, disk)
+            .map(|id| fs.path(id))
+            .as_deref(),
+        Some("/")
+    );
+    assert_eq!(
+        paths(&day07::cheapest_deletion(&fs, disk).unwrap()),
+        ["/a", "/b/c"]
+    );
+
+    let roomy = Disk {
+        capacity: 200,
+        needed: 65,
+    };
+    assert_eq!(day07::smallest_to_delete(&fs, roomy), None);
+    assert_eq!(day07::cheapest_deletion(&fs, roomy), Some(vec![]));
+
+    let full = Disk {
+        capacity: 100,
+        needed: 100,
+    };
+    assert_eq!(paths(&day07::cheapest_deletion(&fs, full).unwrap()), ["/"]);
+    let impossible = Disk {
+        capacity: 100,
+        needed: 101,
+    };
+    assert_eq!(day07::cheapest_deletion(&fs, impossible), None);
+}
+
+#[test]
+fn cleanup_on_a_real_sized_disk() {
+    let fs = day07::parse(concat!(
+        "$ cd /\n$ ls\ndir a\ndir b\ndir d\n$ cd a\n$ ls\n110000000 f\n$ cd ..\n",
+        "$ cd b\n$ ls\n10000000 g\ndir c\n$ cd c\n$ ls\n70000000 h\n$ cd ..\n$ cd ..\n",
+        "$ cd d\n$ ls\n35000000 i",
+    ))
+    .unwrap();
+    let disk = Disk {
+        capacity: 300_000_000,
+        needed: 175_000_000,
+    };
+
+    assert_eq!(disk.to_free(&fs), 100_000_000);
+    assert_eq!(
+        day07::smallest_to_delete(&fs, disk).map(|id| fs.path(id)),
+        Some(String::from("/a"))
+    );
+    let deleted = day07::cheapest_deletion(&fs, disk).unwrap();
+    assert_eq!(
+        deleted.iter().map(|&id| fs.path(id)).collect::<Vec<_>>(),
+        ["/b/c", "/d"]
+    );
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day07>(INPUT, Part::One), "1432936");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day07>(INPUT, Part::Two), "272298");
+}
@@ -0,0 +1,20 @@
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day08::Day08;
+
+const INPUT: &str = include_str!("../input");
+
+fn bench(c: &mut Criterion) {
+    let input = Day08::parse(INPUT).unwrap();
+
+    c.bench_function("day08 parse", |b| b.iter(|| Day08::parse(black_box(INPUT))));
+    c.bench_function("day08 part1", |b| {
+        b.iter(|| Day08::part1(black_box(&input)).map(|answer| answer.to_string()))
+    });
+    c.bench_function("day08 part2", |b| {
+        b.iter(|| Day08::part2(black_box(&input)).map(|answer| answer.to_string()))
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
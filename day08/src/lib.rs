@@ -0,0 +1,110 @@
+use std::fmt;
+
+use aoc_common::{Grid, Pos};
+
+/// Tree heights.
+pub type Forest = Grid<u8>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidHeight,
+    UnevenRow,
+    NotSquare,
+    Empty,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidHeight => write!(f, "invalid tree height, expected a digit"),
+            ErrorKind::UnevenRow => write!(f, "row is not as wide as the first one"),
+            ErrorKind::NotSquare => write!(f, "forest is not square"),
+            ErrorKind::Empty => write!(f, "expected at least one row of trees"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<Forest, ParseError> {
+    let forest = Grid::parse(input, ErrorKind::InvalidHeight, ErrorKind::UnevenRow, |c| {
+        c.to_digit(10).map(|height| height as u8)
+    })?;
+
+    if forest.width() == 0 || forest.height() == 0 {
+        return Err(ParseError::end(ErrorKind::Empty, input));
+    }
+
+    if forest.width() != forest.height() {
+        return Err(ParseError::end(ErrorKind::NotSquare, input));
+    }
+
+    Ok(forest)
+}
+
+pub fn is_visible((x, y): Pos, forest: &Forest) -> bool {
+    let height = forest[(x, y)];
+    let row = forest.row(y);
+
+    row[..x].iter().all(|&tree| tree < height) ||
+    row[(x + 1)..].iter().all(|&tree| tree < height) ||
+    forest.column(x).take(y).all(|&tree| tree < height) ||
+    forest.column(x).skip(y + 1).all(|&tree| tree < height)
+}
+
+pub fn part1(forest: &Forest) -> usize {
+    forest.positions()
+        .filter(|pos| is_visible(*pos, forest))
+        .count()
+}
+
+pub fn scenic_score((x, y): Pos, forest: &Forest) -> usize {
+    fn score<'a>(trees: impl Iterator<Item = &'a u8>, height: u8) -> usize {
+        let mut score = 0;
+        for &tree in trees {
+            score += 1;
+            if tree >= height {
+                break;
+            }
+        }
+
+        score
+    }
+
+    let height = forest[(x, y)];
+    let row = forest.row(y);
+
+    score(row[..x].iter().rev(), height) *
+    score(row[(x + 1)..].iter(), height) *
+    score(forest.column(x).take(y).rev(), height) *
+    score(forest.column(x).skip(y + 1), height)
+}
+
+pub fn part2(forest: &Forest) -> usize {
+    forest.positions()
+        .map(|pos| scenic_score(pos, forest))
+        .max()
+        .expect("parse rejects an empty forest")
+}
+
+pub struct Day08;
+
+impl aoc_common::Solution for Day08 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Forest;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
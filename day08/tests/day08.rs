@@ -0,0 +1,32 @@
+use aoc_common::{answer, Part};
+use day08::{Day08, ErrorKind};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day08>(EXAMPLE, Part::One), "21");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day08>(EXAMPLE, Part::Two), "8");
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(day08::parse("").unwrap_err().kind, ErrorKind::Empty);
+    assert_eq!(day08::parse("12\n3").unwrap_err().kind, ErrorKind::UnevenRow);
+    assert_eq!(day08::parse("12").unwrap_err().kind, ErrorKind::NotSquare);
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day08>(INPUT, Part::One), "1823");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day08>(INPUT, Part::Two), "211680");
+}
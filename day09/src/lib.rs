@@ -0,0 +1,158 @@
+use std::{collections::HashSet, fmt};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Right,
+    Left,
+    Up,
+    Down,
+}
+
+pub struct Move {
+    pub dir: Direction,
+    pub dist: usize,
+}
+
+pub type Pos = (i32, i32);
+
+fn chebyshev_distance((x, y): Pos, (p, q): Pos) -> usize {
+    usize::max(i32::abs(x - p) as usize, i32::abs(y - q) as usize)
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Grid {
+    head: Pos,
+    tail: Pos,
+}
+
+impl Grid {
+    fn follow_tail(&mut self) {
+        match chebyshev_distance(self.head, self.tail) {
+            0 | 1 => (),
+            2 => {
+                let (x, y) = self.head;
+                let (p, q) = self.tail;
+                self.tail = (p + f32::round((x - p) as f32 / 2.0) as i32, q + f32::round((y - q) as f32 / 2.0) as i32);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn move_head(&mut self, dir: Direction) {
+        let (x, y) = self.head;
+
+        self.head = match dir {
+            Direction::Right => (x + 1, y),
+            Direction::Left => (x - 1, y),
+            Direction::Up => (x, y + 1),
+            Direction::Down => (x, y - 1),
+        };
+
+        self.follow_tail();
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingDistance,
+    UnknownDirection,
+    InvalidDistance,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingDistance => write!(f, "expected a direction and a distance"),
+            ErrorKind::UnknownDirection => write!(f, "unknown direction, expected R, L, U or D"),
+            ErrorKind::InvalidDistance => write!(f, "invalid distance"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+    aoc_common::numbered_lines(input)
+        .map(|(number, line)| {
+            let (dir, dist) = line.split_once(' ')
+                .ok_or_else(|| ParseError::line(ErrorKind::MissingDistance, number, line))?;
+
+            let dir = match dir {
+                "R" => Direction::Right,
+                "L" => Direction::Left,
+                "U" => Direction::Up,
+                "D" => Direction::Down,
+                _ => return Err(ParseError::at(ErrorKind::UnknownDirection, number, line, dir)),
+            };
+
+            Ok(Move { dir, dist: aoc_common::parse_at(dist, ErrorKind::InvalidDistance, number, line)? })
+        })
+        .collect()
+}
+
+pub fn part1(moves: &[Move]) -> usize {
+    let mut grid = Grid::default();
+
+    moves.iter()
+        .fold(HashSet::new(), |mut tail_positions, m| {
+            (0..m.dist).for_each(|_| {
+                grid.move_head(m.dir);
+                tail_positions.insert(grid.tail);
+            });
+
+            tail_positions
+        }).len()
+}
+
+pub fn part2(moves: &[Move]) -> usize {
+    let mut grids = [Grid::default(); 9];
+
+    moves.iter()
+        .fold(HashSet::new(), |mut tail_positions, m| {
+            (0..m.dist).for_each(|_| {
+                for i in 0..grids.len() {
+                    if i == 0 {
+                        let grid = &mut grids[i];
+                        grid.move_head(m.dir);
+                        let new_tail = grid.tail;
+                        let next_grid = &mut grids[i + 1];
+                        next_grid.head = new_tail;
+                        next_grid.follow_tail();
+                    } else {
+                        let prev_grid = grids[i - 1];
+                        let grid = &mut grids[i];
+                        grid.head = prev_grid.tail;
+                        grid.follow_tail();
+
+                        if i == 8 {
+                            tail_positions.insert(grid.tail);
+                        }
+                    }
+                }
+            });
+
+            tail_positions
+        }).len()
+}
+
+pub struct Day09;
+
+impl aoc_common::Solution for Day09 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<Move>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day09::Day09;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day09>(EXAMPLE, Part::One), "13");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day09>(EXAMPLE, Part::Two), "1");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day09>(INPUT, Part::One), "6470");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day09>(INPUT, Part::Two), "2658");
+}
@@ -0,0 +1,149 @@
+//! Draws day 10's CRT with any screen and sprite size and saves it as an
+//! image, optionally along with every frame the beam draws on the way.
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_common::{Error, Result, Solution, Source};
+use day10::{Crt, Day10};
+
+const USAGE: &str = "\
+crt [--width <N>] [--height <N>] [--sprite <N>] [--format <pbm | png>] [--scale <N>]
+           [--output <PATH>] [--frames <DIR>] [INPUT | -]";
+
+#[derive(Clone, Copy)]
+enum Format {
+    /// Plain text PBM, which needs no dependencies to read.
+    Pbm,
+    Png,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Pbm => "pbm",
+            Format::Png => "png",
+        }
+    }
+}
+
+struct Args {
+    width: usize,
+    height: usize,
+    sprite_width: usize,
+    format: Format,
+    /// How many image pixels each screen pixel takes in a PNG.
+    scale: usize,
+    /// Where to save the last frame, instead of printing it.
+    output: Option<PathBuf>,
+    /// Directory to save every frame in, one image per cycle.
+    frames: Option<PathBuf>,
+    input: Source,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Args> {
+        let mut parsed = Args {
+            width: Crt::WIDTH,
+            height: Crt::HEIGHT,
+            sprite_width: Crt::SPRITE_WIDTH,
+            format: Format::Pbm,
+            scale: 8,
+            output: None,
+            frames: None,
+            input: Source::File(PathBuf::from(Day10::INPUT)),
+        };
+
+        while let Some(flag) = args.next() {
+            match flag.as_str() {
+                "--width" => parsed.width = size(&mut args, &flag)?,
+                "--height" => parsed.height = size(&mut args, &flag)?,
+                "--sprite" => parsed.sprite_width = size(&mut args, &flag)?,
+                "--scale" => parsed.scale = size(&mut args, &flag)?,
+                "--format" => {
+                    let value = value(&mut args, &flag)?;
+                    parsed.format = match value.as_str() {
+                        "pbm" => Format::Pbm,
+                        "png" => Format::Png,
+                        _ => {
+                            return Err(Error::Argument(format!(
+                                "unknown format `{value}`, expected `pbm` or `png`"
+                            )))
+                        }
+                    };
+                }
+                "--output" => parsed.output = Some(PathBuf::from(value(&mut args, &flag)?)),
+                "--frames" => parsed.frames = Some(PathBuf::from(value(&mut args, &flag)?)),
+                input if !input.starts_with("--") => parsed.input = Source::from_arg(input),
+                _ => return Err(Error::Usage(USAGE.to_string())),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String> {
+    args.next()
+        .ok_or_else(|| Error::Argument(format!("missing value for `{flag}`")))
+}
+
+fn size(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<usize> {
+    let value = value(args, flag)?;
+
+    match value.parse() {
+        Ok(size @ 1..) => Ok(size),
+        _ => Err(Error::Argument(format!(
+            "invalid value `{value}` for `{flag}`, expected a positive number"
+        ))),
+    }
+}
+
+fn save(crt: &Crt, format: Format, scale: usize, path: &Path) -> Result<()> {
+    let image = match format {
+        Format::Pbm => crt.to_pbm().into_bytes(),
+        Format::Png => crt.to_png(scale),
+    };
+
+    std::fs::write(path, image).map_err(|source| Error::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn main() -> ExitCode {
+    aoc_common::run(|| {
+        let args = Args::parse(std::env::args().skip(1))?;
+        let program =
+            Day10::parse(&args.input.read()?).map_err(|err| Error::Parse(Box::new(err)))?;
+
+        if let Some(dir) = &args.frames {
+            std::fs::create_dir_all(dir).map_err(|source| Error::Write {
+                path: dir.clone(),
+                source,
+            })?;
+        }
+
+        let mut crt = Crt::new(args.width, args.height, args.sprite_width);
+        let mut cycle = 0;
+        let mut saved = Ok(());
+        day10::display(&program, &mut crt, |crt| {
+            cycle += 1;
+            if let (Some(dir), Ok(())) = (&args.frames, &saved) {
+                let path = dir.join(format!("{cycle:04}.{}", args.format.extension()));
+                saved = save(crt, args.format, args.scale, &path);
+            }
+        });
+        saved?;
+
+        match &args.output {
+            Some(path) => save(&crt, args.format, args.scale, path),
+            None => {
+                print!("{}", crt.render());
+                Ok(())
+            }
+        }
+    })
+}
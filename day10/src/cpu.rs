@@ -0,0 +1,159 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A register, named by a lowercase letter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Register(u8);
+
+impl Register {
+    pub const X: Register = Register(b'x' - b'a');
+
+    pub fn from_name(name: char) -> Option<Register> {
+        name.is_ascii_lowercase()
+            .then(|| Register(name as u8 - b'a'))
+    }
+
+    pub fn name(self) -> char {
+        char::from(b'a' + self.0)
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Values of all registers. `X` starts at 1, every other register at 0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Registers([i32; 26]);
+
+impl Default for Registers {
+    fn default() -> Self {
+        let mut registers = Registers([0; 26]);
+        registers[Register::X] = 1;
+
+        registers
+    }
+}
+
+impl Index<Register> for Registers {
+    type Output = i32;
+
+    fn index(&self, register: Register) -> &i32 {
+        &self.0[usize::from(register.0)]
+    }
+}
+
+impl IndexMut<Register> for Registers {
+    fn index_mut(&mut self, register: Register) -> &mut i32 {
+        &mut self.0[usize::from(register.0)]
+    }
+}
+
+/// Something the CPU can execute.
+pub trait Instruction: fmt::Debug {
+    /// How many cycles the instruction takes. Its effect shows only after the
+    /// last one.
+    fn cycles(&self) -> usize;
+
+    fn execute(&self, registers: &mut Registers);
+}
+
+pub type Program = Vec<Box<dyn Instruction>>;
+
+/// `noop`: takes a cycle and does nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Noop;
+
+impl Instruction for Noop {
+    fn cycles(&self) -> usize {
+        1
+    }
+
+    fn execute(&self, _: &mut Registers) {}
+}
+
+/// `add<register> <value>`, like `addx 3`: adds the value to the register
+/// after two cycles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Add {
+    pub register: Register,
+    pub value: i32,
+}
+
+impl Instruction for Add {
+    fn cycles(&self) -> usize {
+        2
+    }
+
+    fn execute(&self, registers: &mut Registers) {
+        registers[self.register] += self.value;
+    }
+}
+
+/// Runs a program one cycle at a time.
+pub struct Cpu<'a> {
+    program: &'a [Box<dyn Instruction>],
+    registers: Registers,
+    /// Index of the running instruction.
+    pointer: usize,
+    /// Cycles the running instruction has taken so far.
+    elapsed: usize,
+    /// Number of the last cycle run, counting from 1.
+    cycle: usize,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Box<dyn Instruction>]) -> Cpu<'a> {
+        Cpu {
+            program,
+            registers: Registers::default(),
+            pointer: 0,
+            elapsed: 0,
+            cycle: 0,
+        }
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    /// Number of cycles run so far.
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pointer >= self.program.len()
+    }
+
+    /// Runs the next cycle, calling `during` with its number and the
+    /// registers as they are during that cycle, before the running
+    /// instruction finishes. Returns `false` once the program has ended.
+    pub fn step(&mut self, during: &mut impl FnMut(usize, &Registers)) -> bool {
+        let Some(instruction) = self.program.get(self.pointer) else {
+            return false;
+        };
+
+        self.cycle += 1;
+        during(self.cycle, &self.registers);
+
+        self.elapsed += 1;
+        if self.elapsed >= instruction.cycles() {
+            instruction.execute(&mut self.registers);
+            self.pointer += 1;
+            self.elapsed = 0;
+        }
+
+        true
+    }
+
+    /// Runs the program to its end, calling `during` at every cycle like
+    /// [`step`](Cpu::step).
+    pub fn run(&mut self, mut during: impl FnMut(usize, &Registers)) {
+        while self.step(&mut during) {}
+    }
+}
@@ -0,0 +1,109 @@
+use aoc_common::Grid;
+
+/// The screen the CPU draws on. A beam draws one pixel per cycle, row by row
+/// from the top left, lighting it if the sprite is over it. The sprite is a
+/// horizontal line centered on `X`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crt {
+    pixels: Grid<bool>,
+    sprite_width: usize,
+    /// Index of the pixel the beam draws next.
+    beam: usize,
+}
+
+impl Crt {
+    pub const WIDTH: usize = 40;
+    pub const HEIGHT: usize = 6;
+    pub const SPRITE_WIDTH: usize = 3;
+
+    /// # Panics
+    ///
+    /// If the screen has no pixels.
+    pub fn new(width: usize, height: usize, sprite_width: usize) -> Crt {
+        assert!(width > 0 && height > 0, "the screen needs pixels");
+
+        Crt {
+            pixels: Grid::new(width, height, false),
+            sprite_width,
+            beam: 0,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    /// Draws the pixel under the beam with the sprite centered on `x` and
+    /// moves the beam on, back to the top left after the last pixel. A sprite
+    /// of even width has one more pixel right of `x` than left of it.
+    pub fn draw(&mut self, x: i32) {
+        let pos = (self.beam % self.width(), self.beam / self.width());
+        let left = i64::from(x) - (self.sprite_width as i64 - 1) / 2;
+        let column = pos.0 as i64;
+
+        self.pixels[pos] = (left..left + self.sprite_width as i64).contains(&column);
+        self.beam = (self.beam + 1) % (self.width() * self.height());
+    }
+
+    /// The screen with `#` for lit pixels and spaces for dark ones, one line
+    /// per row.
+    pub fn render(&self) -> String {
+        self.pixels.render(|&lit| if lit { '#' } else { ' ' })
+    }
+
+    /// The screen as a plain PBM image, with lit pixels in black.
+    pub fn to_pbm(&self) -> String {
+        let header = format!("P1\n{} {}\n", self.width(), self.height());
+
+        self.pixels.rows().fold(header, |mut pbm, row| {
+            let row: Vec<&str> = row.iter().map(|&lit| if lit { "1" } else { "0" }).collect();
+            pbm += &row.join(" ");
+            pbm.push('\n');
+            pbm
+        })
+    }
+
+    /// The screen as a greyscale PNG image, with lit pixels in white like on
+    /// the terminal, scaled up `scale` times so that it isn't tiny.
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut data = Vec::with_capacity(width * height);
+        for row in self.pixels.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&lit| std::iter::repeat_n(if lit { 255 } else { 0 }, scale))
+                .collect();
+            for _ in 0..scale {
+                data.extend(&line);
+            }
+        }
+
+        let mut png = vec![];
+        let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        // Writing to memory can only fail on a wrong size, which `data` has
+        // by construction.
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&data))
+            .expect("image data matches the header");
+
+        png
+    }
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Crt::new(Crt::WIDTH, Crt::HEIGHT, Crt::SPRITE_WIDTH)
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+
+mod cpu;
+mod crt;
+
+pub use cpu::{Add, Cpu, Instruction, Noop, Program, Register, Registers};
+pub use crt::Crt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownOpcode,
+    MissingValue,
+    InvalidValue,
+    UnexpectedOperand,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownOpcode => {
+                write!(
+                    f,
+                    "unknown opcode, expected `noop` or `add<register>`, like `addx`"
+                )
+            }
+            ErrorKind::MissingValue => write!(f, "missing value"),
+            ErrorKind::InvalidValue => write!(f, "invalid value"),
+            ErrorKind::UnexpectedOperand => write!(f, "unexpected operand"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+fn parse_instruction(number: usize, line: &str) -> Result<Box<dyn Instruction>, ParseError> {
+    let mut words = line.split_whitespace();
+    let opcode = words.next().unwrap_or(line);
+    let unknown = || ParseError::at(ErrorKind::UnknownOpcode, number, line, opcode);
+
+    let instruction: Box<dyn Instruction> = if opcode == "noop" {
+        Box::new(Noop)
+    } else if let Some(register) = opcode.strip_prefix("add") {
+        let mut name = register.chars();
+        let register = match (name.next().and_then(Register::from_name), name.next()) {
+            (Some(register), None) => register,
+            _ => return Err(unknown()),
+        };
+
+        let value = words.next().ok_or_else(|| {
+            ParseError::new(
+                ErrorKind::MissingValue,
+                number,
+                line.chars().count() + 1,
+                "",
+            )
+        })?;
+        let value = aoc_common::parse_at(value, ErrorKind::InvalidValue, number, line)?;
+
+        Box::new(Add { register, value })
+    } else {
+        return Err(unknown());
+    };
+
+    if let Some(operand) = words.next() {
+        return Err(ParseError::at(
+            ErrorKind::UnexpectedOperand,
+            number,
+            line,
+            operand,
+        ));
+    }
+
+    Ok(instruction)
+}
+
+pub fn parse(input: &str) -> Result<Program, ParseError> {
+    aoc_common::numbered_lines(input)
+        .map(|(number, line)| parse_instruction(number, line))
+        .collect()
+}
+
+/// Sum of the signal strengths, the cycle number times `X`, during the 20th
+/// cycle and every 40 cycles after that.
+pub fn part1(program: &[Box<dyn Instruction>]) -> i32 {
+    let mut strength = 0;
+
+    Cpu::new(program).run(|cycle, registers| {
+        if cycle % 40 == 20 && cycle <= 220 {
+            strength += cycle as i32 * registers[Register::X];
+        }
+    });
+
+    strength
+}
+
+/// Runs the program with `crt` drawing a pixel every cycle, calling `drawn`
+/// after each one.
+pub fn display(program: &[Box<dyn Instruction>], crt: &mut Crt, mut drawn: impl FnMut(&Crt)) {
+    Cpu::new(program).run(|_, registers| {
+        crt.draw(registers[Register::X]);
+        drawn(crt);
+    });
+}
+
+/// What the puzzle's CRT draws, one line per row.
+pub fn render(program: &[Box<dyn Instruction>]) -> String {
+    let mut crt = Crt::default();
+    display(program, &mut crt, |_| {});
+
+    crt.render()
+}
+
+/// The letters on the CRT, or the image itself if it doesn't show letters.
+pub fn part2(program: &[Box<dyn Instruction>]) -> String {
+    aoc_common::ocr::read(&render(program))
+}
+
+pub struct Day10;
+
+impl aoc_common::Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Program;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+
+    fn image(input: &Self::Input, part: aoc_common::Part) -> Option<String> {
+        (part == aoc_common::Part::Two).then(|| render(input))
+    }
+}
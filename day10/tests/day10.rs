@@ -0,0 +1,92 @@
+use aoc_common::{answer, Part};
+use day10::{Cpu, Crt, Day10, Register};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+/// The rendered image without trailing spaces, so the expected images below
+/// don't depend on invisible whitespace.
+fn image(answer: &str) -> String {
+    answer
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day10>(EXAMPLE, Part::One), "13140");
+}
+
+#[test]
+fn part2_example() {
+    let expected = "\
+##  ##  ##  ##  ##  ##  ##  ##  ##  ##
+###   ###   ###   ###   ###   ###   ###
+####    ####    ####    ####    ####
+#####     #####     #####     #####
+######      ######      ######      ####
+#######       #######       #######";
+
+    assert_eq!(image(&answer::<Day10>(EXAMPLE, Part::Two)), expected);
+}
+
+#[test]
+fn registers_during_each_cycle() {
+    let program = day10::parse("noop\naddx 3\naddy -5\nnoop").unwrap();
+    let y = Register::from_name('y').unwrap();
+
+    let mut during = vec![];
+    let mut cpu = Cpu::new(&program);
+    cpu.run(|cycle, registers| during.push((cycle, registers[Register::X], registers[y])));
+
+    assert_eq!(
+        during,
+        [
+            (1, 1, 0),
+            (2, 1, 0),
+            (3, 1, 0),
+            (4, 4, 0),
+            (5, 4, 0),
+            (6, 4, -5)
+        ]
+    );
+    assert!(cpu.is_done());
+    assert_eq!(cpu.cycle(), 6);
+}
+
+#[test]
+fn crt_geometry_and_pbm() {
+    let program = day10::parse("addx 3\nnoop\naddx -3\nnoop\nnoop").unwrap();
+    let mut crt = Crt::new(3, 2, 1);
+    day10::display(&program, &mut crt, |_| {});
+
+    assert_eq!(crt.render(), " # \n   \n");
+    assert_eq!(crt.to_pbm(), "P1\n3 2\n0 1 0\n0 0 0\n");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day10>(INPUT, Part::One), "13760");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day10>(INPUT, Part::Two), "RFKZCPEF");
+}
+
+#[test]
+fn image_instead_of_letters() {
+    let mut answers = vec![];
+    aoc_common::solve::<Day10>(INPUT, &Part::ALL, true, &mut |event| {
+        if let aoc_common::Event::Answered { answer, .. } = event {
+            answers.push(answer);
+        }
+    })
+    .unwrap();
+
+    let program = day10::parse(INPUT).unwrap();
+    assert_eq!(answers, ["13760".to_string(), day10::render(&program)]);
+    assert_eq!(day10::part2(&program), "RFKZCPEF");
+}
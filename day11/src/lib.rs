@@ -0,0 +1,246 @@
+use std::{fmt, rc::Rc};
+
+/// Worry level of an item after inspecting it.
+type Operation = Rc<dyn Fn(u64) -> u64>;
+/// Monkey an item with a given worry level is thrown to.
+type NewMonkey = Rc<dyn Fn(u64) -> usize>;
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    new_monkey: NewMonkey,
+    inspected: usize,
+    divisible_by: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A line of the monkey's description is missing or out of order.
+    Expected(&'static str),
+    InvalidNumber,
+    InvalidOperation,
+    DivisibleByZero,
+    UnknownMonkey,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Expected(prefix) => write!(f, "expected `{prefix}`"),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::InvalidOperation => {
+                write!(f, "expected an operation like `new = old * 3` or `new = old + old`")
+            }
+            ErrorKind::DivisibleByZero => write!(f, "can't test divisibility by zero"),
+            ErrorKind::UnknownMonkey => write!(f, "no monkey with this number"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// A line of a monkey's description: its number, the whole line, and the
+/// text after the expected prefix.
+type Field<'a> = (usize, &'a str, &'a str);
+
+fn field<'a>(
+    monkey: &[(usize, &'a str)],
+    index: usize,
+    prefix: &'static str,
+) -> Result<Field<'a>, ParseError> {
+    let Some(&(number, line)) = monkey.get(index) else {
+        let (last, _) = monkey[monkey.len() - 1];
+
+        return Err(ParseError::new(ErrorKind::Expected(prefix), last + 1, 1, ""));
+    };
+
+    match line.trim_start().strip_prefix(prefix) {
+        Some(rest) => Ok((number, line, rest)),
+        None => Err(ParseError::line(ErrorKind::Expected(prefix), number, line)),
+    }
+}
+
+fn parse_starting_items((number, line, items): Field) -> Result<Vec<u64>, ParseError> {
+    items
+        .split(", ")
+        .map(|item| aoc_common::parse_at(item, ErrorKind::InvalidNumber, number, line))
+        .collect()
+}
+
+fn parse_operation((number, line, operation): Field) -> Result<Operation, ParseError> {
+    let (multiply, second) = match operation.split_once(' ') {
+        Some(("*", second)) => (true, second),
+        Some(("+", second)) => (false, second),
+        _ => return Err(ParseError::line(ErrorKind::InvalidOperation, number, line)),
+    };
+
+    let second_number = match second {
+        "old" => None,
+        _ => Some(aoc_common::parse_at::<u64, _>(
+            second,
+            ErrorKind::InvalidNumber,
+            number,
+            line,
+        )?),
+    };
+
+    let operation = move |item| {
+        let second = match second_number {
+            Some(n) => n,
+            None => item,
+        };
+
+        if multiply {
+            item * second
+        } else {
+            item + second
+        }
+    };
+
+    Ok(Rc::new(operation))
+}
+
+fn parse_new_monkey_fn(
+    (test_number, test_line, test_str): Field,
+    true_field: Field,
+    false_field: Field,
+) -> Result<(NewMonkey, u64), ParseError> {
+    let divisible_by =
+        aoc_common::parse_at(test_str, ErrorKind::InvalidNumber, test_number, test_line)?;
+
+    if divisible_by == 0 {
+        return Err(ParseError::at(
+            ErrorKind::DivisibleByZero,
+            test_number,
+            test_line,
+            test_str,
+        ));
+    }
+
+    let new_monkey_nums: Vec<usize> = [true_field, false_field]
+        .iter()
+        .map(|&(number, line, result)| {
+            aoc_common::parse_at(result, ErrorKind::InvalidNumber, number, line)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let new_monkey = move |item| new_monkey_nums[usize::from(item % divisible_by != 0)];
+
+    Ok((Rc::new(new_monkey), divisible_by))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let groups = aoc_common::numbered_groups(input);
+    let mut targets = vec![];
+
+    let monkeys = groups
+        .iter()
+        .map(|monkey| {
+            field(monkey, 0, "Monkey ")?;
+
+            let items = parse_starting_items(field(monkey, 1, "Starting items: ")?)?;
+            let operation = parse_operation(field(monkey, 2, "Operation: new = old ")?)?;
+
+            let true_field = field(monkey, 4, "If true: throw to monkey ")?;
+            let false_field = field(monkey, 5, "If false: throw to monkey ")?;
+            let (new_monkey, divisible_by) = parse_new_monkey_fn(
+                field(monkey, 3, "Test: divisible by ")?,
+                true_field,
+                false_field,
+            )?;
+
+            targets.extend([true_field, false_field]);
+
+            Ok(Monkey {
+                items,
+                operation,
+                new_monkey,
+                inspected: 0,
+                divisible_by,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (number, line, target) in targets {
+        if target.parse::<usize>().map_or(true, |target| target >= monkeys.len()) {
+            return Err(ParseError::at(ErrorKind::UnknownMonkey, number, line, target));
+        }
+    }
+
+    Ok(monkeys)
+}
+
+fn monkey_throws(monkey: &mut Monkey, divide_by: u64, limit: u64) -> Vec<(usize, u64)> {
+    let throws = monkey
+        .items
+        .iter()
+        .map(|item| {
+            let new_value = ((monkey.operation)(*item) / divide_by) % limit;
+            let new_monkey = (monkey.new_monkey)(new_value);
+
+            monkey.inspected += 1;
+            (new_monkey, new_value)
+        })
+        .collect();
+
+    monkey.items.clear();
+
+    throws
+}
+
+fn monkeys_catch(monkeys: &mut [Monkey], throws: &[(usize, u64)]) {
+    for (num_monkey, item) in throws.iter() {
+        let monkey = &mut monkeys[*num_monkey];
+        monkey.items.push(*item);
+    }
+}
+
+fn top_inspected_product(monkeys: &mut [Monkey], rounds: usize, divide_by: u64) -> usize {
+    let limit = monkeys.iter().map(|monkey| monkey.divisible_by).product();
+
+    (0..rounds).for_each(|_| {
+        (0..monkeys.len()).for_each(|num_monkey| {
+            let monkey = &mut monkeys[num_monkey];
+
+            let throws = monkey_throws(monkey, divide_by, limit);
+            monkeys_catch(monkeys, &throws);
+        });
+    });
+
+    let mut inspected: Vec<usize> = monkeys.iter().map(|monkey| monkey.inspected).collect();
+
+    inspected.sort_unstable();
+    inspected.reverse();
+    inspected.iter().take(2).product()
+}
+
+pub fn part1(monkeys: &[Monkey]) -> usize {
+    top_inspected_product(&mut monkeys.to_vec(), 20, 3)
+}
+
+pub fn part2(monkeys: &[Monkey]) -> usize {
+    top_inspected_product(&mut monkeys.to_vec(), 10_000, 1)
+}
+
+pub struct Day11;
+
+impl aoc_common::Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<Monkey>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day11::Day11;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day11>(EXAMPLE, Part::One), "10605");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day11>(EXAMPLE, Part::Two), "2713310158");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day11>(INPUT, Part::One), "111210");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day11>(INPUT, Part::Two), "15447387620");
+}
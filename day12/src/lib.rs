@@ -0,0 +1,157 @@
+use std::fmt;
+
+use aoc_common::{Grid, Pos, Search};
+
+pub type Height = u8;
+pub type HeightMap = Grid<(char, Height)>;
+pub struct State {
+    pub map: HeightMap,
+    pub start: Pos,
+    pub end: Pos,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidHeight,
+    UnevenRow,
+    MissingStart,
+    MissingEnd,
+    Unreachable,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidHeight => write!(f, "invalid height, expected a lowercase letter, `S` or `E`"),
+            ErrorKind::UnevenRow => write!(f, "row is not as wide as the first one"),
+            ErrorKind::MissingStart => write!(f, "missing start `S`"),
+            ErrorKind::MissingEnd => write!(f, "missing end `E`"),
+            ErrorKind::Unreachable => write!(f, "the end can't be reached"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<State, ParseError> {
+    let heights = Grid::parse(input, ErrorKind::InvalidHeight, ErrorKind::UnevenRow, |c| {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+    })?;
+
+    let find = |target| heights.iter().find(|(_, &c)| c == target).map(|(pos, _)| pos);
+    let start = find('S').ok_or_else(|| ParseError::end(ErrorKind::MissingStart, input))?;
+    let end = find('E').ok_or_else(|| ParseError::end(ErrorKind::MissingEnd, input))?;
+
+    let map = heights.map(|&c| match c {
+        'S' => ('a', 0),
+        'E' => ('z', b'z' - b'a'),
+        c => (c, (c as Height) - b'a'),
+    });
+
+    Ok(State { map, start, end })
+}
+
+impl State {
+    fn reachable(&self, pos: &Pos) -> Vec<(Pos, usize)> {
+        let source_height = self.map[*pos].1;
+
+        self.map.neighbours4(*pos)
+            .filter(|&pos| source_height + 1 >= self.map[pos].1)
+            .map(|pos| (pos, 1))
+            .collect()
+    }
+
+    /// Fewest steps from any of `starts` to the end.
+    fn fewest_steps(&self, starts: impl IntoIterator<Item = Pos>) -> Option<usize> {
+        let visited = Grid::new(self.map.width(), self.map.height(), false);
+
+        Search::with_visited(starts, visited)
+            .astar(|p| self.reachable(p), |p| distance(p, &self.end), |p| *p == self.end)
+            .map(|path| path.cost)
+    }
+}
+
+// fn backtrack(state: &State, pos: Pos, target: Pos, seen: &Vec<Pos>) -> Option<Vec<Pos>> {
+    // let (path, len) = state.reachable(pos).iter()
+        // .filter_map(|reach| {
+            // if seen.contains(reach) {
+                // return None;
+            // }
+
+            // let mut path = seen.clone();
+            // path.push(*reach);
+
+            // if *reach == target {
+                // let len = path.len();
+                // return Some((path, len));
+            // }
+
+            // if let Some(new_path) = backtrack(state, *reach, target, &path) {
+                // let len = new_path.len();
+                // return Some((new_path, len));
+            // }
+
+            // None
+        // })
+        // .fold((vec![], 0), |(acc, acc_len), (path, path_len)| {
+            // if acc_len == 0 || path_len < acc_len {
+                // (path, path_len)
+            // } else {
+                // (acc, acc_len)
+            // }
+        // });
+
+    // if len > 0 {
+        // return Some(path);
+    // }
+
+    // None
+// }
+
+fn distance(first: &Pos, second: &Pos) -> usize {
+    first.0.abs_diff(second.0) + first.1.abs_diff(second.1)
+}
+
+/// Fewest steps from the start to the end, if it can be reached.
+pub fn part1(state: &State) -> Option<usize> {
+    state.fewest_steps([state.start])
+}
+
+/// Fewest steps from any lowest square to the end, if it can be reached.
+pub fn part2(state: &State) -> Option<usize> {
+    let starts: Vec<Pos> = state.map.iter()
+        .filter(|(_, (c, _))| *c == 'a')
+        .map(|(pos, _)| pos)
+        .collect();
+
+    state.fewest_steps(starts)
+}
+
+impl State {
+    /// Points at the end, for when a part can't reach it.
+    fn unreachable(&self) -> ParseError {
+        ParseError::new(ErrorKind::Unreachable, self.end.1 + 1, self.end.0 + 1, "E")
+    }
+}
+
+pub struct Day12;
+
+impl aoc_common::Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = State;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        part1(input).ok_or_else(|| input.unreachable())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        part2(input).ok_or_else(|| input.unreachable())
+    }
+}
@@ -0,0 +1,55 @@
+use aoc_common::{answer, Part};
+use day12::{Day12, ErrorKind};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day12>(EXAMPLE, Part::One), "31");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day12>(EXAMPLE, Part::Two), "29");
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        day12::parse("Sa\nab").err().unwrap().kind,
+        ErrorKind::MissingEnd
+    );
+    assert_eq!(
+        day12::parse("Sa\nabE").err().unwrap().kind,
+        ErrorKind::UnevenRow
+    );
+}
+
+#[test]
+fn unreachable_end() {
+    let walled_in = day12::parse("SaE\nabc").unwrap();
+    assert_eq!(day12::part1(&walled_in), None);
+    assert_eq!(day12::part2(&walled_in), None);
+
+    let err = aoc_common::solve::<Day12>("SaE\nabc", &[Part::One], false, &mut |_| {}).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid input: line 1, column 3: the end can't be reached (found `E`)"
+    );
+
+    // `S` is walled in, but the `a` after the wall can climb to the end.
+    let state = day12::parse("SzabcdefghijklmnopqrstuvwxyE").unwrap();
+    assert_eq!(day12::part1(&state), None);
+    assert_eq!(day12::part2(&state), Some(25));
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day12>(INPUT, Part::One), "468");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day12>(INPUT, Part::Two), "459");
+}
@@ -0,0 +1,212 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use nom::{
+    branch::alt,
+    character::complete::char,
+    character::complete::u8,
+    combinator::{cut, map},
+    multi::separated_list0,
+    sequence::delimited,
+    IResult,
+};
+
+#[derive(Clone, Debug)]
+pub enum Packet {
+    List(Vec<Packet>),
+    Item(u8),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Packet::List(first_items), Packet::List(second_items)) => {
+                for i in 0..(usize::max(first_items.len(), second_items.len())) {
+                    match (first_items.get(i), second_items.get(i)) {
+                        (None, None | Some(_)) => return Ordering::Less,
+                        (Some(_), None) => return Ordering::Greater,
+                        (Some(left), Some(right)) => match left.cmp(right) {
+                            Ordering::Less => return Ordering::Less,
+                            Ordering::Equal => continue,
+                            Ordering::Greater => return Ordering::Greater,
+                        },
+                    }
+                }
+
+                Ordering::Equal
+            }
+            (left @ Packet::List(_), Packet::Item(d)) => {
+                let list_single_item = Packet::List(vec![Packet::Item(*d)]);
+                left.cmp(&list_single_item)
+            }
+            (Packet::Item(d), right @ Packet::List(_)) => {
+                let list_single_item = Packet::List(vec![Packet::Item(*d)]);
+                list_single_item.cmp(right)
+            }
+            (Packet::Item(left), Packet::Item(right)) => match (left, right) {
+                (l, r) if l == r => Ordering::Equal,
+                (l, r) if l < r => Ordering::Less,
+                (l, r) if l > r => Ordering::Greater,
+                _ => unreachable!(),
+            },
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::List(l0), Self::List(r0)) => l0 == r0,
+            (Self::Item(l0), Self::Item(r0)) => l0 == r0,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Packet {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidPacket,
+    TrailingText,
+    ExpectedPair,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidPacket => write!(f, "expected `[`, `]`, `,` or a number"),
+            ErrorKind::TrailingText => write!(f, "unexpected text after the packet"),
+            ErrorKind::ExpectedPair => write!(f, "expected exactly two packets per pair"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+fn parse_packet(number: usize, line: &str) -> Result<Packet, ParseError> {
+    match packet(line) {
+        Ok(("", packet)) => Ok(packet),
+        Ok((rest, _)) => Err(ParseError::at(ErrorKind::TrailingText, number, line, rest)),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => Err(ParseError::at(
+            ErrorKind::InvalidPacket,
+            number,
+            line,
+            err.input,
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(
+            ErrorKind::InvalidPacket,
+            number,
+            line.len() + 1,
+            "",
+        )),
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        parse_packet(1, string)
+    }
+}
+
+fn list(input: &str) -> IResult<&str, Vec<Packet>> {
+    delimited(
+        char('['),
+        separated_list0(char(','), packet),
+        cut(char(']')),
+    )(input)
+}
+
+fn packet(input: &str) -> IResult<&str, Packet> {
+    use Packet::{Item, List};
+
+    alt((map(list, List), map(u8, Item)))(input)
+}
+
+pub type Pair = (Packet, Packet);
+
+pub fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+    aoc_common::numbered_groups(input)
+        .iter()
+        .map(|pair| match pair[..] {
+            [(first_number, first), (second_number, second)] => Ok((
+                parse_packet(first_number, first)?,
+                parse_packet(second_number, second)?,
+            )),
+            _ => {
+                let (number, line) = pair[0];
+
+                Err(ParseError::line(ErrorKind::ExpectedPair, number, line))
+            }
+        })
+        .collect()
+}
+
+pub fn part1(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (first, second))| match first.cmp(second) {
+            Ordering::Less => Some(i + 1),
+            Ordering::Equal => unreachable!(),
+            Ordering::Greater => None,
+        })
+        .sum()
+}
+
+pub fn part2(pairs: &[Pair]) -> usize {
+    let mut packets: Vec<Packet> = pairs
+        .iter()
+        .flat_map(|(left, right)| vec![left.clone(), right.clone()])
+        .collect();
+
+    let dividers: Vec<_> = [2u8, 6u8]
+        .into_iter()
+        .map(|d| Packet::List(vec![Packet::List(vec![Packet::Item(d)])]))
+        .collect();
+
+    packets.append(&mut dividers.clone());
+
+    packets.sort();
+
+    packets
+        .iter()
+        .enumerate()
+        .filter_map(|(i, packet)| {
+            if dividers.contains(packet) {
+                Some(i + 1)
+            } else {
+                None
+            }
+        })
+        .product()
+}
+
+pub struct Day13;
+
+impl aoc_common::Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<Pair>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
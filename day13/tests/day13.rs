@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day13::Day13;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day13>(EXAMPLE, Part::One), "13");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day13>(EXAMPLE, Part::Two), "140");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day13>(INPUT, Part::One), "5330");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day13>(INPUT, Part::Two), "27648");
+}
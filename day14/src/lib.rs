@@ -0,0 +1,280 @@
+use std::fmt;
+
+use aoc_common::Grid;
+
+#[derive(Clone, Copy)]
+pub enum Cell {
+    Sand,
+    Air,
+    Rock
+}
+
+pub type Coord = i32;
+
+/// Slice of the cave wide enough for the sand to pile up to the source, with
+/// room for the floor two rows below the lowest rock.
+#[derive(Clone)]
+pub struct Cave {
+    cells: Grid<Cell>,
+    /// Column of the leftmost cells.
+    x_min: Coord,
+    floor: bool,
+}
+
+impl Cave {
+    pub fn get_cell(&self, x: Coord, y: Coord) -> Option<Cell> {
+        let pos = (usize::try_from(x - self.x_min).ok()?, usize::try_from(y).ok()?);
+
+        self.cells.get(pos).copied()
+    }
+
+    fn set_cell(&mut self, (x, y): (Coord, Coord), cell: Cell) {
+        self.cells[((x - self.x_min) as usize, y as usize)] = cell;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingComma,
+    InvalidCoordinate,
+    DiagonalLine,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingComma => write!(f, "expected a point like `498,4`"),
+            ErrorKind::InvalidCoordinate => write!(f, "invalid coordinate"),
+            ErrorKind::DiagonalLine => write!(f, "rock line is neither horizontal nor vertical"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+fn parse_path(number: usize, line: &str) -> Result<Vec<(Coord, Coord)>, ParseError> {
+    let mut path: Vec<(Coord, Coord)> = vec![];
+
+    for point in line.split(" -> ") {
+        let (x, y) = point.split_once(',')
+            .ok_or_else(|| ParseError::at(ErrorKind::MissingComma, number, line, point))?;
+        let (x, y) = (
+            aoc_common::parse_at(x, ErrorKind::InvalidCoordinate, number, line)?,
+            aoc_common::parse_at(y, ErrorKind::InvalidCoordinate, number, line)?,
+        );
+
+        if let Some(&(last_x, last_y)) = path.last() {
+            if last_x != x && last_y != y {
+                return Err(ParseError::at(ErrorKind::DiagonalLine, number, line, point));
+            }
+        }
+
+        path.push((x, y));
+    }
+
+    Ok(path)
+}
+
+pub fn parse(input: &str) -> Result<Cave, ParseError> {
+    let paths: Vec<Vec<(Coord, Coord)>> =
+        aoc_common::numbered_lines(input)
+        .map(|(number, line)| parse_path(number, line))
+        .collect::<Result<_, _>>()?;
+
+    let end_points =
+        paths.iter()
+            .fold((Coord::MAX, 0, 0, 0), |acc, segments| {
+                let (mut x_min, mut x_max, mut y_min, mut y_max) = acc;
+                for segment in segments.iter() {
+                        let (x, y) = segment;
+
+                        x_min = Coord::min(*x, x_min);
+                        x_max = Coord::max(*x, x_max);
+                        y_min = Coord::min(*y, y_min);
+                        y_max = Coord::max(*y, y_max);
+                    }
+
+                (x_min, x_max, y_min, y_max)
+            });
+
+    let (x_min, x_max, _, y_max) = end_points;
+
+    // Sand piles up in a triangle below the source at (500, 0).
+    let x_min = x_min.min(500 - y_max - 2);
+    let x_max = x_max.max(500 + y_max + 2);
+    let mut cells = Grid::new((x_max - x_min + 1) as usize, (y_max + 3) as usize, Cell::Air);
+
+    for path in &paths {
+            path.windows(2)
+                .for_each(|segment| {
+                    let first = segment[0];
+                    let second = segment[1];
+                    let sx = Coord::min(first.0, second.0);
+                    let sy = Coord::min(first.1, second.1);
+                    let ex = Coord::max(first.0, second.0);
+                    let ey = Coord::max(first.1, second.1);
+
+                    if sx == ex {
+                        let x = sx;
+                        (sy..=ey).for_each(|y| {
+                            cells[((x - x_min) as usize, y as usize)] = Cell::Rock;
+                        });
+                    } else if sy == ey {
+                        let y = sy;
+                        (sx..=ex).for_each(|x| {
+                            cells[((x - x_min) as usize, y as usize)] = Cell::Rock;
+                        });
+                    }
+                });
+        }
+
+    Ok(Cave { cells, x_min, floor: false })
+}
+
+enum MoveResult {
+    Moved((Coord, Coord)),
+    Stopped,
+    Full,
+    Abyss,
+}
+
+fn move_sand(grid: &mut Cave, coords: (Coord, Coord)) -> MoveResult {
+    let (sand_x, sand_y) = (coords.0, coords.1);
+
+    let displacements = [(sand_x, sand_y + 1), (sand_x - 1, sand_y + 1), (sand_x + 1, sand_y + 1)];
+
+    // for (x, y) in displacements {
+        // if !grid.floor && (x < 0 || y < 0) {
+            // return MoveResult::Abyss;
+        // }
+
+        // if let Some(cell) = grid.get_cell(x, y) {
+            // match cell {
+                // Cell::Air => {
+                    // grid.cells.remove(&(sand_x, sand_y));
+                    // grid.cells.insert((x, y), Cell::Sand);
+                    // return MoveResult::Moved((x, y));
+                // },
+                // Cell::Sand | Cell::Rock => continue,
+            // }
+        // }
+
+        // if grid.floor {
+            // if coords == (500, 0) {
+                // return MoveResult::Full;
+            // }
+
+            // return MoveResult::Stopped;
+        // }
+
+        // return MoveResult::Abyss;
+    // }
+
+    // MoveResult::Stopped
+
+    if grid.floor {
+        for (x, y) in displacements {
+            if let Some(cell) = grid.get_cell(x, y) {
+                match cell {
+                    Cell::Air => {
+                        grid.set_cell((sand_x, sand_y), Cell::Air);
+                        grid.set_cell((x, y), Cell::Sand);
+                        return MoveResult::Moved((x, y));
+                    },
+                    Cell::Sand | Cell::Rock => continue,
+                }
+            }
+        }
+
+        if coords == (500, 0) {
+            return MoveResult::Full;
+        }
+
+        return MoveResult::Stopped;
+    }
+
+    for (x, y) in displacements {
+        if x < 0 || y < 0 {
+            return MoveResult::Abyss;
+        }
+
+        if let Some(cell) = grid.get_cell(x, y) {
+            match cell {
+                Cell::Air => {
+                    grid.set_cell((sand_x, sand_y), Cell::Air);
+                    grid.set_cell((x, y), Cell::Sand);
+                    return MoveResult::Moved((x, y));
+                },
+                Cell::Sand | Cell::Rock => continue,
+            }
+        }
+
+        return MoveResult::Abyss;
+    }
+
+    MoveResult::Stopped
+}
+
+fn add_sand(grid: &mut Cave) -> bool {
+    let sand_start_pos = (500, 0);
+    grid.set_cell(sand_start_pos, Cell::Sand);
+
+    let mut sand_pos = sand_start_pos;
+
+    loop {
+        match move_sand(grid, sand_pos) {
+            MoveResult::Moved(pos) => sand_pos = pos,
+            MoveResult::Stopped => break,
+            MoveResult::Full | MoveResult::Abyss => return false,
+        }
+    }
+
+    true
+}
+
+pub fn part1(grid: &Cave) -> usize {
+    let mut grid = grid.clone();
+
+    let mut i = 0;
+    while add_sand(&mut grid) {
+        i += 1;
+    }
+
+    i
+}
+
+pub fn part2(grid: &Cave) -> usize {
+    let mut grid = grid.clone();
+    grid.floor = true;
+    let bottom = grid.cells.height() - 1;
+    grid.cells.row_mut(bottom).fill(Cell::Rock);
+
+    let mut i = 0;
+    while add_sand(&mut grid) {
+        i += 1;
+    }
+
+    i + 1
+}
+
+pub struct Day14;
+
+impl aoc_common::Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Cave;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day14::Day14;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day14>(EXAMPLE, Part::One), "24");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day14>(EXAMPLE, Part::Two), "93");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day14>(INPUT, Part::One), "799");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day14>(INPUT, Part::Two), "29076");
+}
@@ -0,0 +1,198 @@
+use std::{collections::HashSet, fmt};
+
+pub type Coord = i32;
+pub type Pos = (Coord, Coord);
+pub type Beacon = Pos;
+pub type Sensor = Pos;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidReport,
+    InvalidPosition,
+    InvalidCoordinate,
+    Empty,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidReport => write!(
+                f,
+                "expected `Sensor at <position>: closest beacon is at <position>`"
+            ),
+            ErrorKind::InvalidPosition => write!(f, "expected a position like `x=2, y=18`"),
+            ErrorKind::InvalidCoordinate => write!(f, "invalid coordinate"),
+            ErrorKind::Empty => write!(f, "expected at least one sensor report"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+fn parse_position(position: &str, number: usize, line: &str) -> Result<Pos, ParseError> {
+    let (x, y) = position
+        .strip_prefix("x=")
+        .and_then(|position| position.split_once(", y="))
+        .ok_or_else(|| ParseError::at(ErrorKind::InvalidPosition, number, line, position))?;
+
+    Ok((
+        aoc_common::parse_at(x, ErrorKind::InvalidCoordinate, number, line)?,
+        aoc_common::parse_at(y, ErrorKind::InvalidCoordinate, number, line)?,
+    ))
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Sensor, Beacon)>, ParseError> {
+    if input.trim().is_empty() {
+        return Err(ParseError::end(ErrorKind::Empty, input));
+    }
+
+    aoc_common::numbered_lines(input)
+        .map(|(number, line)| {
+            let (sensor, beacon) = line
+                .strip_prefix("Sensor at ")
+                .and_then(|line| line.split_once(": closest beacon is at "))
+                .ok_or_else(|| ParseError::line(ErrorKind::InvalidReport, number, line))?;
+
+            Ok((
+                parse_position(sensor, number, line)?,
+                parse_position(beacon, number, line)?,
+            ))
+        })
+        .collect()
+}
+
+fn get_endpoints(input: &[(Sensor, Beacon)]) -> (Coord, Coord) {
+    let ((min_x, max_x), max_d) = input.iter().fold(
+        ((Coord::MAX, Coord::MIN), 0),
+        |((min_x, max_x), max_d), (sensor, beacon)| {
+            (
+                (
+                    sensor.0.min(sensor.1.min(min_x)),
+                    sensor.0.max(sensor.1.max(max_x)),
+                ),
+                manhattan_distance(*sensor, *beacon).max(max_d),
+            )
+        },
+    );
+
+    (min_x - max_d, max_x + max_d)
+}
+
+pub fn manhattan_distance(first: Pos, second: Pos) -> i32 {
+    (first.0 - second.0).abs() + (first.1 - second.1).abs()
+}
+
+pub fn is_in_range(sensor: Sensor, beacon: Beacon, pos: Pos) -> bool {
+    manhattan_distance(sensor, beacon) >= manhattan_distance(sensor, pos)
+}
+
+/// Positions in row `y` where no beacon can be.
+pub fn count_no_beacon(input: &[(Sensor, Beacon)], y: Coord) -> usize {
+    let (x_min, x_max) = get_endpoints(input);
+
+    let horizontal_covered_positions = (x_min..=x_max)
+        .filter(|x| {
+            input
+                .iter()
+                .any(|(sensor, beacon)| is_in_range(*sensor, *beacon, (*x, y)))
+        })
+        .count();
+
+    let beacons_at_y = input
+        .iter()
+        .filter_map(
+            |(_, beacon)| {
+                if beacon.1 == y {
+                    Some(*beacon)
+                } else {
+                    None
+                }
+            },
+        )
+        .collect::<HashSet<Beacon>>()
+        .len();
+
+    horizontal_covered_positions - beacons_at_y
+}
+
+fn get_adjacent(sensor: Sensor, beacon: Beacon, min: i32, max: i32) -> Vec<Pos> {
+    let distance = manhattan_distance(sensor, beacon) + 1;
+
+    let mut points = vec![];
+
+    for i in 0..=distance {
+        let start = ((sensor.0 - distance), sensor.1);
+        if start.0 + i >= min && start.0 + i <= max {
+            if start.1 - i >= min && start.1 - i <= max {
+                points.push((start.0 + i, start.1 - i));
+            }
+            if start.1 + i >= min && start.1 + i <= max {
+                points.push((start.0 + i, start.1 + i));
+            }
+        }
+
+        let start = ((sensor.0 + distance), sensor.1);
+        if start.0 - i >= min && start.0 - i <= max {
+            if start.1 - i >= min && start.1 - i <= max {
+                points.push((start.0 - i, start.1 - i));
+            }
+            if start.1 + i >= min && start.1 + i <= max {
+                points.push((start.0 - i, start.1 + i));
+            }
+        }
+    }
+
+    points
+}
+
+pub fn part1(input: &[(Sensor, Beacon)]) -> usize {
+    count_no_beacon(input, 2_000_000)
+}
+
+/// Tuning frequency of the only position with both coordinates between 0 and
+/// `max` that no sensor covers.
+pub fn tuning_frequency(input: &[(Sensor, Beacon)], max: Coord) -> usize {
+    let min = 0;
+
+    let not_covered: Vec<Pos> = input
+        .iter()
+        .flat_map(|(sensor, beacon)| get_adjacent(*sensor, *beacon, min, max))
+        .filter(|point| {
+            let (x, y) = point;
+
+            input
+                .iter()
+                .all(|(sensor, beacon)| !is_in_range(*sensor, *beacon, (*x, *y)))
+        })
+        .collect();
+
+    let (x, y) = not_covered[0];
+
+    (x as usize) * 4_000_000 + (y as usize)
+}
+
+pub fn part2(input: &[(Sensor, Beacon)]) -> usize {
+    tuning_frequency(input, 4_000_000)
+}
+
+pub struct Day15;
+
+impl aoc_common::Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<(Sensor, Beacon)>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_common::{answer, Part};
+use day15::{Day15, ErrorKind};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+// The example asks about row 10 and positions up to 20 instead of the real
+// puzzle's 2000000 and 4000000.
+
+#[test]
+fn part1_example() {
+    let input = day15::parse(EXAMPLE).unwrap();
+
+    assert_eq!(day15::count_no_beacon(&input, 10), 26);
+}
+
+#[test]
+fn part2_example() {
+    let input = day15::parse(EXAMPLE).unwrap();
+
+    assert_eq!(day15::tuning_frequency(&input, 20), 56_000_011);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(day15::parse("").unwrap_err().kind, ErrorKind::Empty);
+    let err = day15::parse("Sensor at x=2, y=18: closest beacon is at x=-2, y=a").unwrap_err();
+    assert_eq!((err.kind, err.column), (ErrorKind::InvalidCoordinate, 51));
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day15>(INPUT, Part::One), "5127797");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day15>(INPUT, Part::Two), "12518502636475");
+}
@@ -0,0 +1,25 @@
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day16::Day16;
+
+const INPUT: &str = include_str!("../input");
+
+fn bench(c: &mut Criterion) {
+    let input = Day16::parse(INPUT).unwrap();
+
+    c.bench_function("day16 parse", |b| b.iter(|| Day16::parse(black_box(INPUT))));
+    c.bench_function("day16 part1", |b| {
+        b.iter(|| Day16::part1(black_box(&input)).map(|answer| answer.to_string()))
+    });
+    c.bench_function("day16 part2", |b| {
+        b.iter(|| Day16::part2(black_box(&input)).map(|answer| answer.to_string()))
+    });
+}
+
+// A single run takes seconds, so take the fewest samples criterion allows.
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench
+}
+criterion_main!(benches);
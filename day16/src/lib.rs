@@ -0,0 +1,342 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use aoc_common::Search;
+
+pub type Identifier = String;
+
+#[derive(Debug)]
+pub struct Valve {
+    pub flow_rate: i32,
+    pub tunnels: Vec<Identifier>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidValve,
+    InvalidFlowRate,
+    DuplicateValve,
+    UnknownValve,
+    MissingStart,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidValve => write!(
+                f,
+                "expected `Valve <name> has flow rate=<rate>; tunnels lead to valves <names>`"
+            ),
+            ErrorKind::InvalidFlowRate => write!(f, "invalid flow rate"),
+            ErrorKind::DuplicateValve => write!(f, "valve is described twice"),
+            ErrorKind::UnknownValve => write!(f, "tunnel leads to an undescribed valve"),
+            ErrorKind::MissingStart => write!(f, "missing start valve `AA`"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<HashMap<Identifier, Valve>, ParseError> {
+    let mut acc = HashMap::new();
+    let mut all_tunnels = vec![];
+
+    for (number, line) in aoc_common::numbered_lines(input) {
+        let (valve, tunnels) =
+            if let Some((valve, tunnels)) = line.split_once("; tunnels lead to valves ") {
+                (valve, tunnels)
+            } else if let Some((valve, tunnel)) = line.split_once("; tunnel leads to valve ") {
+                (valve, tunnel)
+            } else {
+                return Err(ParseError::line(ErrorKind::InvalidValve, number, line));
+            };
+        let (identifier, flow_rate) = valve
+            .strip_prefix("Valve ")
+            .and_then(|valve| valve.split_once(" has flow rate="))
+            .ok_or_else(|| ParseError::line(ErrorKind::InvalidValve, number, line))?;
+
+        let flow_rate = aoc_common::parse_at(flow_rate, ErrorKind::InvalidFlowRate, number, line)?;
+
+        let tunnels: Vec<&str> = tunnels.split(", ").collect();
+        all_tunnels.extend(tunnels.iter().map(|&tunnel| (number, line, tunnel)));
+
+        if acc.contains_key(identifier) {
+            return Err(ParseError::at(
+                ErrorKind::DuplicateValve,
+                number,
+                line,
+                identifier,
+            ));
+        }
+
+        let tunnels = tunnels.into_iter().map(str::to_string).collect();
+        let valve = Valve { flow_rate, tunnels };
+        acc.insert(identifier.to_string(), valve);
+    }
+
+    for (number, line, tunnel) in all_tunnels {
+        if !acc.contains_key(tunnel) {
+            return Err(ParseError::at(
+                ErrorKind::UnknownValve,
+                number,
+                line,
+                tunnel,
+            ));
+        }
+    }
+
+    if !acc.contains_key("AA") {
+        return Err(ParseError::end(ErrorKind::MissingStart, input));
+    }
+
+    Ok(acc)
+}
+
+/// Minutes it takes to walk from `source` to `target`, if it can be reached.
+pub fn get_cost(
+    valves: &HashMap<Identifier, Valve>,
+    source: &Identifier,
+    target: &Identifier,
+) -> Option<usize> {
+    Search::new(source)
+        .bfs(|&valve| &valves[valve].tunnels, |&valve| valve == target)
+        .map(|found| found.cost)
+}
+
+/// Travel time between every pair of valves worth opening, and from `AA`.
+/// Pairs with no tunnels from one to the other are left out.
+pub fn get_all_paths_costs(
+    valves: &HashMap<Identifier, Valve>,
+) -> HashMap<Identifier, HashMap<Identifier, usize>> {
+    let mut path_costs: HashMap<Identifier, HashMap<Identifier, usize>> = HashMap::new();
+
+    for (id_s, valve_s) in valves {
+        if valve_s.flow_rate == 0 && id_s != "AA" {
+            continue;
+        }
+
+        let mut source_map = HashMap::new();
+
+        for (id_t, valve_t) in valves {
+            if id_t != "AA" && (id_s == id_t || valve_t.flow_rate == 0) {
+                continue;
+            }
+
+            if let Some(cost) = get_cost(valves, id_s, id_t) {
+                source_map.insert(id_t.to_string(), cost);
+            }
+        }
+
+        path_costs.insert(id_s.to_string(), source_map);
+    }
+
+    path_costs
+}
+
+fn valid_permutations(
+    costs: &HashMap<String, HashMap<String, usize>>,
+    remaining: &Vec<String>,
+    current: &mut Vec<Identifier>,
+    current_cost: usize,
+    max_cost: usize,
+) -> Vec<Vec<Identifier>> {
+    if remaining.is_empty() {
+        return vec![current.clone()];
+    }
+
+    let mut new = vec![];
+
+    let mut end = true;
+    for r in remaining {
+        let mut remaining = remaining.clone();
+        let index = remaining.iter().position(|x| x == r).unwrap();
+        remaining.remove(index);
+
+        let last = current.last().unwrap();
+
+        if r == last {
+            continue;
+        }
+
+        let Some(add_cost) = costs[last].get(r) else {
+            continue;
+        };
+        let cost = current_cost + add_cost + 1;
+
+        if cost > max_cost {
+            continue;
+        }
+
+        end = false;
+
+        current.push(r.clone());
+
+        for p in valid_permutations(costs, &remaining, current, cost, max_cost) {
+            new.push(p);
+        }
+
+        current.pop();
+    }
+
+    if end {
+        new.push(current.clone());
+    }
+
+    new
+}
+
+fn get_score(
+    costs: &HashMap<String, HashMap<String, usize>>,
+    valves: &HashMap<Identifier, Valve>,
+    path: &[Identifier],
+    max_minute: i32,
+) -> usize {
+    let mut minute: i32 = 1;
+    let mut releasing_pm: i32 = 0;
+    let mut released: i32 = 0;
+
+    let mut current = &path[0];
+    for node in &path[1..] {
+        let path_costs = costs.get(current).unwrap().get(node).unwrap();
+
+        let flow_rate = valves.get(node).unwrap().flow_rate;
+
+        current = node;
+        let time_diff = (path_costs + 1) as i32;
+        minute += time_diff;
+        released += releasing_pm * time_diff;
+        releasing_pm += flow_rate;
+    }
+
+    while minute <= max_minute {
+        minute += 1;
+        released += releasing_pm;
+    }
+
+    released as usize
+}
+
+pub fn part1(valves: &HashMap<Identifier, Valve>) -> usize {
+    let path_costs = get_all_paths_costs(valves);
+
+    let valve_ids: Vec<Identifier> = path_costs.keys().cloned().collect();
+
+    let aa = String::from("AA");
+    let mut without_aa = valve_ids;
+    let aa_index = without_aa.iter().position(|x| **x == aa).unwrap();
+    without_aa.remove(aa_index);
+    let mut start = vec![aa];
+    let permutations = valid_permutations(&path_costs, &without_aa, &mut start, 0, 30);
+    aoc_common::debug!("{} candidate paths", permutations.len());
+
+    permutations
+        .iter()
+        .map(|perm| get_score(&path_costs, valves, perm, 30))
+        .max()
+        .unwrap()
+}
+
+fn valid_permutations2(
+    valves: &HashMap<Identifier, Valve>,
+    costs: &HashMap<String, HashMap<String, usize>>,
+    remaining: &Vec<String>,
+) -> usize {
+    let mut start = vec![String::from("AA")];
+
+    // Either of us may stop early to leave valves to the other, so every
+    // prefix of a path is a candidate too.
+    let paths: HashSet<Vec<Identifier>> = valid_permutations(costs, remaining, &mut start, 0, 26)
+        .iter()
+        .flat_map(|path| (1..=path.len()).map(|len| path[..len].to_vec()))
+        .collect();
+    aoc_common::debug!("{} candidate paths", paths.len());
+
+    let mut paths_with_scores: Vec<(usize, Vec<Identifier>)> = paths
+        .into_iter()
+        .map(|path| {
+            let score = get_score(costs, valves, &path, 26);
+            (score, path)
+        })
+        .collect();
+
+    paths_with_scores.sort_by(|path1, path2| path1.0.partial_cmp(&path2.0).unwrap());
+    paths_with_scores.reverse();
+    let max_score = paths_with_scores.first().unwrap().0;
+
+    // Taking the best path alone scores `max_score`, so a better pair has a
+    // path scoring at least half of it, and together they score more.
+    let aa = String::from("AA");
+    let mut pairs = vec![];
+    for (my_score, my_path) in &paths_with_scores {
+        if my_score * 2 < max_score {
+            break;
+        }
+
+        'x: for (elephant_score, elephant_path) in &paths_with_scores {
+            if my_score + elephant_score < max_score {
+                break;
+            }
+
+            for my_node in my_path {
+                if *my_node == aa {
+                    continue;
+                }
+
+                for elephant_node in elephant_path {
+                    if *my_node == *elephant_node {
+                        continue 'x;
+                    }
+                }
+            }
+
+            pairs.push((my_path, elephant_path));
+        }
+    }
+
+    aoc_common::debug!("{} disjoint pairs of paths", pairs.len());
+
+    let mut max_score = 0;
+    for (first, second) in pairs {
+        let combined_score =
+            get_score(costs, valves, first, 26) + get_score(costs, valves, second, 26);
+        max_score = max_score.max(combined_score);
+    }
+
+    max_score
+}
+
+pub fn part2(valves: &HashMap<Identifier, Valve>) -> usize {
+    let path_costs = get_all_paths_costs(valves);
+
+    let valve_ids: Vec<Identifier> = path_costs.keys().cloned().collect();
+
+    let aa = String::from("AA");
+    let mut without_aa = valve_ids;
+    let aa_index = without_aa.iter().position(|x| **x == aa).unwrap();
+    without_aa.remove(aa_index);
+    valid_permutations2(valves, &path_costs, &without_aa)
+}
+
+pub struct Day16;
+
+impl aoc_common::Solution for Day16 {
+    const DAY: u8 = 16;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = HashMap<Identifier, Valve>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
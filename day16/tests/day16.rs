@@ -0,0 +1,38 @@
+use aoc_common::{answer, Part};
+use day16::Day16;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day16>(EXAMPLE, Part::One), "1651");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day16>(EXAMPLE, Part::Two), "1707");
+}
+
+#[ignore = "takes minutes; run with `cargo test -- --ignored`"]
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day16>(INPUT, Part::One), "1828");
+}
+
+#[ignore = "takes minutes; run with `cargo test -- --ignored`"]
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day16>(INPUT, Part::Two), "2292");
+}
+
+#[test]
+fn unreachable_valves() {
+    // `CC` has a tunnel to `AA`, but none lead back to it.
+    let input = "Valve AA has flow rate=0; tunnels lead to valves BB, DD
+Valve BB has flow rate=10; tunnel leads to valve AA
+Valve CC has flow rate=20; tunnel leads to valve AA
+Valve DD has flow rate=5; tunnel leads to valve AA";
+    assert_eq!(answer::<Day16>(input, Part::One), "405");
+    assert_eq!(answer::<Day16>(input, Part::Two), "360");
+}
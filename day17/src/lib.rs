@@ -0,0 +1,404 @@
+use std::fmt;
+
+use aoc_common::Grid;
+
+#[derive(Debug)]
+pub enum Instruction {
+    Left,
+    Right,
+    Down,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidJet,
+    MissingJets,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidJet => write!(f, "invalid jet, expected `<` or `>`"),
+            ErrorKind::MissingJets => write!(f, "missing jet pattern"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let instructions: Vec<Instruction> = aoc_common::numbered_lines(input)
+        .flat_map(|(number, line)| {
+            line.trim_end()
+                .chars()
+                .enumerate()
+                .map(move |(i, c)| match c {
+                    '<' => Ok(Instruction::Left),
+                    '>' => Ok(Instruction::Right),
+                    _ => Err(ParseError::new(ErrorKind::InvalidJet, number, i + 1, c)),
+                })
+        })
+        .collect::<Result<_, _>>()?;
+
+    if instructions.is_empty() {
+        return Err(ParseError::end(ErrorKind::MissingJets, input));
+    }
+
+    Ok(instructions)
+}
+
+type Pos = (i32, i32);
+
+const WIDTH: usize = 7;
+
+/// Cells taken by rocks that came to rest, with the floor as the first row.
+/// Only grows as high as the highest rock.
+type Chamber = Grid<bool>;
+
+/// Whether a rock can be at `(x, y)`: between the walls, above the floor and
+/// not where another rock came to rest.
+fn is_free(chamber: &Chamber, (x, y): Pos) -> bool {
+    (0..WIDTH as i32).contains(&x)
+        && y >= 0
+        && !chamber
+            .get((x as usize, y as usize))
+            .copied()
+            .unwrap_or(false)
+}
+
+fn settle(chamber: &mut Chamber, (x, y): Pos) {
+    while chamber.height() <= y as usize {
+        chamber.push_row([false; WIDTH]);
+    }
+
+    chamber[(x as usize, y as usize)] = true;
+}
+
+#[derive(Clone)]
+struct Shape {
+    dx: i32,
+    dy: i32,
+    height: i32,
+    coords: Vec<Pos>,
+}
+
+fn get_shapes() -> Vec<Shape> {
+    let minus = vec![(0, 0), (1, 0), (2, 0), (3, 0)];
+    let plus = vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)];
+    let reverse_l = vec![(0, 0), (2, 1), (2, 2), (1, 0), (2, 0)];
+    let line = vec![(0, 0), (0, 1), (0, 2), (0, 3)];
+    let square = vec![(0, 0), (1, 0), (0, 1), (1, 1)];
+
+    [
+        (minus, 1),
+        (plus, 3),
+        (reverse_l, 3),
+        (line, 4),
+        (square, 2),
+    ]
+    .iter()
+    .map(|(coords, height)| Shape {
+        coords: coords.clone(),
+        dx: 2,
+        dy: 0,
+        height: *height,
+    })
+    .collect()
+}
+
+impl Shape {
+    fn can_move(&mut self, instruction: &Instruction, chamber: &Chamber) -> bool {
+        let (dx, dy) = match instruction {
+            Instruction::Left => (self.dx - 1, self.dy),
+            Instruction::Right => (self.dx + 1, self.dy),
+            Instruction::Down => (self.dx, self.dy - 1),
+        };
+
+        let hit_something = self
+            .coords
+            .iter()
+            .any(|(x, y)| !is_free(chamber, (x + dx, y + dy)));
+
+        if hit_something {
+            match instruction {
+                Instruction::Left | Instruction::Right => return true,
+                Instruction::Down => return false,
+            }
+        }
+
+        self.dx = dx;
+        self.dy = dy;
+
+        true
+    }
+}
+
+fn run_instructions(instructions: &[Instruction], target_rocks_stopped: usize) -> (usize, Chamber) {
+    let shapes = get_shapes();
+
+    let mut turn = 0;
+
+    let mut current_instruction_index = 0;
+
+    let mut current_shape_index = 0;
+    let mut current_shape = shapes[0].clone();
+
+    let mut hightest_point = 0;
+    current_shape.dy = hightest_point + current_shape.height + 3 - 1;
+
+    let mut chamber = Chamber::new(WIDTH, 0, false);
+    let mut rocks_stopped = 0;
+
+    loop {
+        if rocks_stopped == target_rocks_stopped {
+            break;
+        }
+
+        let instruction = if turn % 2 == 0 {
+            let current = &instructions[current_instruction_index];
+
+            current_instruction_index += 1;
+            current_instruction_index %= instructions.len();
+
+            current
+        } else {
+            &Instruction::Down
+        };
+
+        turn += 1;
+
+        let can_move = current_shape.can_move(instruction, &chamber);
+        if can_move {
+        } else {
+            rocks_stopped += 1;
+            for (x, y) in current_shape.coords {
+                let pos = (x + current_shape.dx, y + current_shape.dy);
+                settle(&mut chamber, pos);
+                hightest_point = hightest_point.max(pos.1 + 1);
+            }
+
+            current_shape_index += 1;
+            current_shape_index %= shapes.len();
+            current_shape = shapes[current_shape_index].clone();
+            current_shape.dy = hightest_point + 3;
+        }
+    }
+
+    (hightest_point as usize, chamber)
+}
+
+pub fn part1(instructions: &[Instruction]) -> usize {
+    run_instructions(instructions, 2022).0
+}
+
+pub fn part2(instructions: &[Instruction]) -> usize {
+    let (hightest_point, chamber) = run_instructions(instructions, 3800);
+
+    let rocks_stopped_target: usize = 1_000_000_000_000;
+    let (layers_before_period, layers_per_period, rocks_before_period, rocks_per_period) =
+        find_periods(instructions, &chamber, hightest_point);
+    aoc_common::info!(
+        "period of {} rocks and {} layers after {} rocks and {} layers",
+        rocks_per_period,
+        layers_per_period,
+        rocks_before_period,
+        layers_before_period
+    );
+
+    let rocks_needed = rocks_stopped_target - rocks_before_period;
+    let periods_needed = rocks_needed / rocks_per_period;
+    aoc_common::debug!("periods needed: {}", periods_needed);
+
+    let rocks_left = rocks_needed % rocks_per_period;
+    aoc_common::debug!("rocks left: {}", rocks_left);
+    if rocks_left == 0 {
+        layers_before_period + (layers_per_period * periods_needed)
+    } else {
+        let x = rocks_left + rocks_before_period;
+        let y = get_layers_for_rocks(instructions, x) - layers_before_period;
+        layers_before_period + (layers_per_period * periods_needed) + y
+    }
+}
+
+fn get_layers_for_rocks(instructions: &[Instruction], x: usize) -> usize {
+    let shapes = get_shapes();
+
+    let mut turn = 0;
+
+    let mut current_instruction_index = 0;
+
+    let mut current_shape_index = 0;
+    let mut current_shape = shapes[0].clone();
+
+    let mut hightest_point = 0;
+    current_shape.dy = hightest_point + current_shape.height + 3 - 1;
+
+    let mut chamber = Chamber::new(WIDTH, 0, false);
+    let mut rocks_stopped = 0;
+
+    loop {
+        if rocks_stopped == x {
+            break;
+        }
+
+        let instruction = if turn % 2 == 0 {
+            let current = &instructions[current_instruction_index];
+
+            current_instruction_index += 1;
+            current_instruction_index %= instructions.len();
+
+            current
+        } else {
+            &Instruction::Down
+        };
+
+        turn += 1;
+
+        let can_move = current_shape.can_move(instruction, &chamber);
+        if can_move {
+        } else {
+            rocks_stopped += 1;
+            for (x, y) in current_shape.coords {
+                let pos = (x + current_shape.dx, y + current_shape.dy);
+                settle(&mut chamber, pos);
+                hightest_point = hightest_point.max(pos.1 + 1);
+            }
+
+            current_shape_index += 1;
+            current_shape_index %= shapes.len();
+            current_shape = shapes[current_shape_index].clone();
+            current_shape.dy = hightest_point + 3;
+        }
+    }
+
+    hightest_point as usize
+}
+
+fn find_rocks_period(
+    instructions: &[Instruction],
+    layers_before_period: usize,
+    layers_per_period: usize,
+) -> (usize, usize) {
+    let shapes = get_shapes();
+
+    let mut turn = 0;
+
+    let mut current_instruction_index = 0;
+
+    let mut current_shape_index = 0;
+    let mut current_shape = shapes[0].clone();
+
+    let mut hightest_point = 0;
+    current_shape.dy = hightest_point + current_shape.height + 3 - 1;
+
+    let mut chamber = Chamber::new(WIDTH, 0, false);
+    let mut rocks_stopped = 0;
+
+    let mut rocks_before_period = 0;
+    let rocks_per_period;
+
+    loop {
+        if (hightest_point as usize) == layers_before_period && rocks_before_period == 0 {
+            rocks_before_period = rocks_stopped;
+        }
+
+        if (hightest_point as usize) <= (layers_before_period + layers_per_period) {
+        } else {
+            rocks_per_period = rocks_stopped - 1 - rocks_before_period;
+            break;
+        }
+
+        let instruction = if turn % 2 == 0 {
+            let current = &instructions[current_instruction_index];
+
+            current_instruction_index += 1;
+            current_instruction_index %= instructions.len();
+
+            current
+        } else {
+            &Instruction::Down
+        };
+
+        turn += 1;
+
+        let can_move = current_shape.can_move(instruction, &chamber);
+        if can_move {
+        } else {
+            rocks_stopped += 1;
+            for (x, y) in current_shape.coords {
+                let pos = (x + current_shape.dx, y + current_shape.dy);
+                settle(&mut chamber, pos);
+                hightest_point = hightest_point.max(pos.1 + 1);
+            }
+
+            current_shape_index += 1;
+            current_shape_index %= shapes.len();
+            current_shape = shapes[current_shape_index].clone();
+            current_shape.dy = hightest_point + 3;
+        }
+    }
+
+    (rocks_before_period, rocks_per_period)
+}
+
+fn find_periods(
+    instructions: &[Instruction],
+    chamber: &Chamber,
+    hightest_point: usize,
+) -> (usize, usize, usize, usize) {
+    let grid: Vec<&[bool]> = chamber.rows().take(hightest_point).collect();
+
+    let window_size = 20;
+    for (window_index, rows1) in grid.windows(window_size).enumerate() {
+        for (other_index, rows2) in grid.windows(window_size).enumerate() {
+            if window_index == other_index {
+                continue;
+            }
+
+            let mut same = true;
+            for i in 0..window_size {
+                if rows1[i] != rows2[i] {
+                    same = false;
+                    break;
+                }
+            }
+
+            if same {
+                let layers_per_period = other_index - window_index;
+                let layers_before_period = window_index;
+                let (rocks_before_period, rocks_per_period) =
+                    find_rocks_period(instructions, layers_before_period, layers_per_period);
+                return (
+                    layers_before_period,
+                    layers_per_period,
+                    rocks_before_period,
+                    rocks_per_period,
+                );
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+pub struct Day17;
+
+impl aoc_common::Solution for Day17 {
+    const DAY: u8 = 17;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<Instruction>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
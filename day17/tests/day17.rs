@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day17::Day17;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day17>(EXAMPLE, Part::One), "3068");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day17>(EXAMPLE, Part::Two), "1514285714288");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day17>(INPUT, Part::One), "3175");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day17>(INPUT, Part::Two), "1555113636385");
+}
@@ -0,0 +1,134 @@
+#![allow(clippy::cast_sign_loss)]
+
+use std::{collections::HashSet, fmt};
+
+use aoc_common::Search;
+
+pub type Coord = i8;
+pub type Pos = (Coord, Coord, Coord);
+
+#[derive(Eq, Hash, PartialEq)]
+pub struct Cube {
+    pub x: Coord,
+    pub y: Coord,
+    pub z: Coord,
+}
+impl Cube {
+    pub fn is_adjacent(&self, other: &Cube) -> bool {
+        (self.x == other.x && self.y == other.y && (self.z - other.z).abs() == 1)
+            || (self.x == other.x && (self.y - other.y).abs() == 1 && self.z == other.z)
+            || ((self.x - other.x).abs() == 1 && self.y == other.y && self.z == other.z)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    ExpectedThreeCoordinates,
+    InvalidCoordinate,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::ExpectedThreeCoordinates => write!(f, "expected a cube like `2,2,2`"),
+            ErrorKind::InvalidCoordinate => write!(f, "invalid coordinate"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<Vec<Cube>, ParseError> {
+    aoc_common::numbered_lines(input)
+        .map(|(number, line)| {
+            let coords: Vec<Coord> = line
+                .split(',')
+                .map(|coord| aoc_common::parse_at(coord, ErrorKind::InvalidCoordinate, number, line))
+                .collect::<Result<_, _>>()?;
+
+            match coords[..] {
+                [x, y, z] => Ok(Cube { x, y, z }),
+                _ => Err(ParseError::line(ErrorKind::ExpectedThreeCoordinates, number, line)),
+            }
+        })
+        .collect()
+}
+
+pub fn total_surface_area(cubes: &[Cube]) -> usize {
+    cubes.iter().fold(0, |acc, cube1| {
+        acc + cubes.iter().fold(6, |acc, cube2| {
+            if cube1.is_adjacent(cube2) {
+                acc - 1
+            } else {
+                acc
+            }
+        })
+    })
+}
+
+pub fn part1(cubes: &[Cube]) -> usize {
+    total_surface_area(cubes)
+}
+
+fn get_all_outside_air(cubes: &[Cube], x_max: Coord, y_max: Coord, z_max: Coord) -> HashSet<Pos> {
+    let cubes_positions: &HashSet<Pos> = &cubes.iter().map(|cube| (cube.x, cube.y, cube.z)).collect();
+
+    let displacements = [ (1, 0, 0), (-1, 0, 0), (0, 1, 0), (0, -1, 0), (0, 0, 1), (0, 0, -1) ];
+
+    Search::new((0, 0, 0)).flood(|&(x, y, z)| {
+        displacements.iter()
+            .map(move |(dx, dy, dz)| (x + dx, y + dy, z + dz))
+            .filter(move |n @ &(nx, ny, nz)| {
+                nx >= 0 && ny >= 0 && nz >= 0 && nx <= x_max && ny <= y_max && nz <= z_max
+                    && !cubes_positions.contains(n)
+            })
+    })
+}
+
+pub fn part2(cubes: &[Cube]) -> usize {
+    let (x_max, y_max, z_max) = cubes.iter().fold((0, 0, 0), |(x, y, z), cube| {
+        (x.max(cube.x), y.max(cube.y), z.max(cube.z))
+    });
+
+    let cube_positions: HashSet<Pos> = cubes.iter().map(|cube| (cube.x, cube.y, cube.z)).collect();
+    let outside_air_positions: HashSet<Pos> = get_all_outside_air(cubes, x_max, y_max, z_max);
+
+    let mut inside_air: Vec<Cube> = vec![];
+    for x in 0..x_max {
+        for y in 0..y_max {
+            for z in 0..z_max {
+                if cube_positions.contains(&(x, y, z)) {
+                    continue;
+                }
+
+                if !outside_air_positions.contains(&(x, y, z)) {
+                    inside_air.push(Cube { x, y, z });
+                }
+            }
+        }
+    }
+
+    total_surface_area(cubes) - total_surface_area(&inside_air)
+}
+
+pub struct Day18;
+
+impl aoc_common::Solution for Day18 {
+    const DAY: u8 = 18;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<Cube>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
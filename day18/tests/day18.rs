@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day18::Day18;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day18>(EXAMPLE, Part::One), "64");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day18>(EXAMPLE, Part::Two), "58");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day18>(INPUT, Part::One), "4604");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day18>(INPUT, Part::Two), "2604");
+}
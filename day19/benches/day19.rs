@@ -0,0 +1,20 @@
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day19::Day19;
+
+const INPUT: &str = include_str!("../input");
+
+fn bench(c: &mut Criterion) {
+    let input = Day19::parse(INPUT).unwrap();
+
+    c.bench_function("day19 parse", |b| b.iter(|| Day19::parse(black_box(INPUT))));
+    c.bench_function("day19 part1", |b| {
+        b.iter(|| Day19::part1(black_box(&input)).map(|answer| answer.to_string()))
+    });
+    c.bench_function("day19 part2", |b| {
+        b.iter(|| Day19::part2(black_box(&input)).map(|answer| answer.to_string()))
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
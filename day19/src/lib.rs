@@ -0,0 +1,206 @@
+use std::fmt;
+
+use regex::{Captures, Regex};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidBlueprint,
+    InvalidCost,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidBlueprint => write!(f, "expected a blueprint like `Blueprint 1: Each ore robot costs 4 ore. ...`"),
+            ErrorKind::InvalidCost => write!(f, "invalid cost"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<Vec<BluePrint>, ParseError> {
+    let re =
+        Regex::new(
+            r"Blueprint \d+: Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian."
+            ).unwrap();
+
+    aoc_common::numbered_lines(input)
+        .map(|(number, line)| {
+            let captures = re.captures(line)
+                .ok_or_else(|| ParseError::line(ErrorKind::InvalidBlueprint, number, line))?;
+
+            let match_to_u8 = |captures: &Captures, index| {
+                aoc_common::parse_at::<u8, _>(captures.get(index).unwrap().as_str(), ErrorKind::InvalidCost, number, line)
+            };
+
+            let ore_robot_cost = (match_to_u8(&captures, 1)?, 0, 0);
+            let clay_robot_cost = (match_to_u8(&captures, 2)?, 0, 0);
+            let obsidian_robot_cost = (match_to_u8(&captures, 3)?, match_to_u8(&captures, 4)?, 0);
+            let geode_robot_cost = (match_to_u8(&captures, 5)?, 0, match_to_u8(&captures, 6)?);
+
+            let max_costs = [ore_robot_cost, clay_robot_cost, obsidian_robot_cost, geode_robot_cost].iter()
+                .fold((0, 0, 0), |(max_ore, max_clay, max_obsidian), (ore_cost, clay_cost, obsidian_cost)| {
+                    (max_ore.max(*ore_cost), max_clay.max(*clay_cost), max_obsidian.max(*obsidian_cost))
+                });
+
+            Ok(BluePrint { ore_robot_cost, clay_robot_cost, obsidian_robot_cost, geode_robot_cost, max_costs })
+        })
+        .collect()
+}
+
+/// Amounts of ore, clay and obsidian.
+pub type MaterialCost = (u8, u8, u8);
+type RobotCount = (u8, u8, u8, u8);
+type MaterialCount = (u32, u32, u32, u32);
+
+
+#[derive(Debug)]
+pub struct BluePrint {
+    pub ore_robot_cost: MaterialCost,
+    pub clay_robot_cost: MaterialCost,
+    pub obsidian_robot_cost: MaterialCost,
+    pub geode_robot_cost: MaterialCost,
+    /// Most of each material any robot costs.
+    pub max_costs: MaterialCost,
+}
+
+/// Minutes of waiting until `robots` have produced `cost` on top of `have`,
+/// or `None` if they never will.
+fn wait_for(cost: u8, have: u32, robots: u8) -> Option<u32> {
+    let cost = u32::from(cost);
+    if have >= cost {
+        Some(0)
+    } else if robots == 0 {
+        None
+    } else {
+        Some((cost - have).div_ceil(u32::from(robots)))
+    }
+}
+
+/// Depth-first search over which robot to build next, skipping the minutes
+/// spent waiting for it.
+fn recurse(
+    current_robots: RobotCount,
+    current_material: MaterialCount,
+    minutes_left: u32,
+    blueprint: &BluePrint,
+    max_found: &mut u32,
+    searched: &mut usize,
+) {
+    *searched += 1;
+
+    let (ore_robots, clay_robots, obsidian_robots, geode_robots) = current_robots;
+    let (ore, clay, obsidian, geode) = current_material;
+
+    // Building nothing more still collects from the geode robots we have.
+    *max_found = (*max_found).max(geode + u32::from(geode_robots) * minutes_left);
+
+    // Even a new geode robot every remaining minute can't beat what we have.
+    let maximum_possible = geode
+        + u32::from(geode_robots) * minutes_left
+        + minutes_left * minutes_left.saturating_sub(1) / 2;
+    if maximum_possible <= *max_found {
+        return;
+    }
+
+    let (max_ore_cost, max_clay_cost, max_obsidian_cost) = blueprint.max_costs;
+
+    let options = [
+        (blueprint.geode_robot_cost, true),
+        (blueprint.obsidian_robot_cost, obsidian_robots < max_obsidian_cost),
+        (blueprint.clay_robot_cost, clay_robots < max_clay_cost),
+        (blueprint.ore_robot_cost, ore_robots < max_ore_cost),
+    ];
+
+    for (i, &((ore_cost, clay_cost, obsidian_cost), useful)) in options.iter().enumerate() {
+        if !useful {
+            continue;
+        }
+
+        let wait = [
+            wait_for(ore_cost, ore, ore_robots),
+            wait_for(clay_cost, clay, clay_robots),
+            wait_for(obsidian_cost, obsidian, obsidian_robots),
+        ]
+        .into_iter()
+        .try_fold(0, |wait, needed| needed.map(|needed| wait.max(needed)));
+
+        // The robot is done one minute after we start building it, and only
+        // helps if it has time left to collect.
+        let Some(minutes) = wait
+            .map(|wait| wait + 1)
+            .filter(|&minutes| minutes < minutes_left)
+        else {
+            continue;
+        };
+
+        let new_robots = match i {
+            0 => (ore_robots, clay_robots, obsidian_robots, geode_robots + 1),
+            1 => (ore_robots, clay_robots, obsidian_robots + 1, geode_robots),
+            2 => (ore_robots, clay_robots + 1, obsidian_robots, geode_robots),
+            3 => (ore_robots + 1, clay_robots, obsidian_robots, geode_robots),
+            _ => unreachable!(),
+        };
+
+        let new_material = (
+            ore + u32::from(ore_robots) * minutes - u32::from(ore_cost),
+            clay + u32::from(clay_robots) * minutes - u32::from(clay_cost),
+            obsidian + u32::from(obsidian_robots) * minutes - u32::from(obsidian_cost),
+            geode + u32::from(geode_robots) * minutes,
+        );
+
+        recurse(new_robots, new_material, minutes_left - minutes, blueprint, max_found, searched);
+    }
+}
+
+impl BluePrint {
+    pub fn get_max_geodes(&self, minutes: u8) -> usize {
+        let mut geodes = 0;
+        let mut searched = 0;
+        recurse((1, 0, 0, 0), (0, 0, 0, 0), u32::from(minutes), self, &mut geodes, &mut searched);
+        aoc_common::debug!("{} states searched for {} geodes", searched, geodes);
+
+        geodes as usize
+    }
+}
+
+pub fn part1(blueprints: &[BluePrint]) -> usize {
+    (0..blueprints.len()).map(|i| {
+            let blueprint = &blueprints[i];
+            aoc_common::info!("blueprint {} of {}", i + 1, blueprints.len());
+            (i + 1) * blueprint.get_max_geodes(24)
+        })
+        .sum()
+}
+
+pub fn part2(blueprints: &[BluePrint]) -> usize {
+    let count = blueprints.len().min(3);
+    blueprints.iter().take(3).enumerate().map(|(i, blueprint)| {
+            aoc_common::info!("blueprint {} of {}", i + 1, count);
+            blueprint.get_max_geodes(32)
+        })
+        .product()
+}
+
+pub struct Day19;
+
+impl aoc_common::Solution for Day19 {
+    const DAY: u8 = 19;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<BluePrint>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
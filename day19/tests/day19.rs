@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day19::Day19;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day19>(EXAMPLE, Part::One), "33");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day19>(EXAMPLE, Part::Two), "3472");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day19>(INPUT, Part::One), "1528");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day19>(INPUT, Part::Two), "16926");
+}
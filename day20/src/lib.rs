@@ -0,0 +1,96 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidNumber,
+    MissingZero,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::MissingZero => write!(f, "the grove coordinates need a `0`"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers: Vec<i64> = aoc_common::numbered_lines(input)
+        .map(|(number, line)| aoc_common::parse_at(line, ErrorKind::InvalidNumber, number, line))
+        .collect::<Result<_, _>>()?;
+
+    if !numbers.contains(&0) {
+        return Err(ParseError::end(ErrorKind::MissingZero, input));
+    }
+
+    Ok(numbers)
+}
+
+/// Sum of the grove coordinates after mixing the numbers `rotations` times.
+pub fn decrypt(input: &[i64], rotations: usize, decryption_key: i64) -> i64 {
+    let input: Vec<i64> = input.iter().map(|x| *x * decryption_key).collect();
+
+    let mut coords_with_original_position: Vec<(usize, i64)> =
+        input.iter().enumerate().map(|(i, x)| (i, *x)).collect();
+
+    let len = input.len();
+
+    (0..rotations).for_each(|_| {
+        (0..input.len()).for_each(|i| {
+            let current = input[i];
+            let position = coords_with_original_position
+                .iter()
+                .position(|(original_index, _)| *original_index == i)
+                .unwrap();
+            coords_with_original_position.remove(position);
+            #[allow(clippy::cast_possible_truncation)]
+            let new_position = ((position as i64) + current).rem_euclid(len as i64 - 1) as usize;
+
+            coords_with_original_position.insert(new_position, (i, current));
+        });
+    });
+
+    let final_coords: Vec<i64> = coords_with_original_position
+        .iter()
+        .map(|(_, x)| *x)
+        .collect();
+
+    let zero_positon = final_coords.iter().position(|x| *x == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|index| final_coords.get((zero_positon + index) % len).unwrap())
+        .sum()
+}
+
+pub fn part1(input: &[i64]) -> i64 {
+    decrypt(input, 1, 1)
+}
+
+pub fn part2(input: &[i64]) -> i64 {
+    decrypt(input, 10, 811_589_153)
+}
+
+pub struct Day20;
+
+impl aoc_common::Solution for Day20 {
+    const DAY: u8 = 20;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<i64>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day20::Day20;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day20>(EXAMPLE, Part::One), "3");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day20>(EXAMPLE, Part::Two), "1623178306");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day20>(INPUT, Part::One), "7713");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day20>(INPUT, Part::Two), "1664569352803");
+}
@@ -0,0 +1,233 @@
+use std::{collections::HashMap, fmt};
+
+pub type Monkey = String;
+
+#[derive(Clone, Debug)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Clone, Debug)]
+pub enum Job {
+    Yell(i64),
+    Math(Monkey, Monkey, Operation),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingJob,
+    InvalidNumber,
+    DuplicateMonkey,
+    UnknownMonkey,
+    /// `root` or `humn` is missing.
+    MissingMonkey(&'static str),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingJob => write!(f, "expected a monkey and its job like `root: pppw + sjmn`"),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::DuplicateMonkey => write!(f, "monkey has two jobs"),
+            ErrorKind::UnknownMonkey => write!(f, "monkey without a job"),
+            ErrorKind::MissingMonkey(monkey) => write!(f, "missing monkey `{monkey}`"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<HashMap<Monkey, Job>, ParseError> {
+    let mut monkey_business = HashMap::new();
+    let mut operands = vec![];
+
+    for (number, line) in aoc_common::numbered_lines(input) {
+        let (monkey, job) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::line(ErrorKind::MissingJob, number, line))?;
+
+        let math = [
+            (" + ", Operation::Add),
+            (" - ", Operation::Subtract),
+            (" * ", Operation::Multiply),
+            (" / ", Operation::Divide),
+        ]
+        .into_iter()
+        .find_map(|(symbol, operation)| {
+            let (monkey1, monkey2) = job.split_once(symbol)?;
+            operands.extend([(number, line, monkey1), (number, line, monkey2)]);
+
+            Some(Job::Math(monkey1.to_string(), monkey2.to_string(), operation))
+        });
+
+        let job = match math {
+            Some(math) => math,
+            None => Job::Yell(aoc_common::parse_at(job, ErrorKind::InvalidNumber, number, line)?),
+        };
+
+        if monkey_business.insert(monkey.to_string(), job).is_some() {
+            return Err(ParseError::at(ErrorKind::DuplicateMonkey, number, line, monkey));
+        }
+    }
+
+    for (number, line, operand) in operands {
+        if !monkey_business.contains_key(operand) {
+            return Err(ParseError::at(ErrorKind::UnknownMonkey, number, line, operand));
+        }
+    }
+
+    for monkey in ["root", "humn"] {
+        if !monkey_business.contains_key(monkey) {
+            return Err(ParseError::end(ErrorKind::MissingMonkey(monkey), input));
+        }
+    }
+
+    Ok(monkey_business)
+}
+
+pub fn evaluate(monkey_business: &HashMap<Monkey, Job>, job: &Job) -> i64 {
+    match job {
+        Job::Yell(value) => *value,
+        Job::Math(left_monkey, right_monkey, operation) => {
+            let values = [left_monkey, right_monkey]
+                .map(|monkey| evaluate(monkey_business, monkey_business.get(monkey).unwrap()));
+
+            match operation {
+                Operation::Add => values[0] + values[1],
+                Operation::Subtract => values[0] - values[1],
+                Operation::Multiply => values[0] * values[1],
+                Operation::Divide => values[0] / values[1],
+            }
+        }
+    }
+}
+
+pub fn part1(monkey_business: &HashMap<Monkey, Job>) -> i64 {
+    let root = Monkey::from("root");
+
+    evaluate(monkey_business, monkey_business.get(&root).unwrap())
+}
+
+fn try_evaluate(monkey_business: &HashMap<Monkey, Job>, job: &Job) -> Option<i64> {
+    match job {
+        Job::Yell(value) => Some(*value),
+        Job::Math(left_monkey, right_monkey, operation) => {
+            let values = [left_monkey, right_monkey].map(|monkey| {
+                monkey_business
+                    .get(monkey)
+                    .and_then(|job| try_evaluate(monkey_business, job))
+            });
+
+            match (values[0], values[1]) {
+                (Some(left_value), Some(right_value)) => match operation {
+                    Operation::Add => Some(left_value + right_value),
+                    Operation::Subtract => Some(left_value - right_value),
+                    Operation::Multiply => Some(left_value * right_value),
+                    Operation::Divide => Some(left_value / right_value),
+                },
+                _ => None,
+            }
+        }
+    }
+}
+
+fn simplify(monkey_business: &mut HashMap<Monkey, Job>) {
+    let copy = monkey_business.clone();
+
+    for (monkey, job) in &copy {
+        match job {
+            Job::Yell(_) => continue,
+            Job::Math(left_monkey, right_monkey, operation) => {
+                if left_monkey == "humn" || right_monkey == "humn" {
+                    continue;
+                }
+
+                let values = [left_monkey, right_monkey]
+                    .map(|monkey| try_evaluate(&copy, monkey_business.get(monkey).unwrap()));
+
+                if let (Some(left_value), Some(right_value)) = (values[0], values[1]) {
+                    let folded_value = match operation {
+                        Operation::Add => left_value + right_value,
+                        Operation::Subtract => left_value - right_value,
+                        Operation::Multiply => left_value * right_value,
+                        Operation::Divide => left_value / right_value,
+                    };
+
+                    monkey_business.remove(left_monkey);
+                    monkey_business.remove(right_monkey);
+                    monkey_business.insert(monkey.to_string(), Job::Yell(folded_value));
+                }
+            }
+        }
+    }
+}
+
+fn monkey_job_to_string(monkey_business: &HashMap<Monkey, Job>, monkey: &Monkey) -> String {
+    if monkey == "humn" {
+        return String::from("x");
+    }
+
+    let job = monkey_business.get(monkey).unwrap();
+    match job {
+        Job::Yell(value) => value.to_string(),
+        Job::Math(left_monkey, right_monkey, operation) => {
+            let left_equation = monkey_job_to_string(monkey_business, left_monkey);
+            let right_equation = monkey_job_to_string(monkey_business, right_monkey);
+
+            let operator = match operation {
+                Operation::Add => '+',
+                Operation::Subtract => '-',
+                Operation::Multiply => '*',
+                Operation::Divide => '/',
+            };
+
+            format!("({left_equation} {operator} {right_equation})")
+        }
+    }
+}
+
+pub fn part2(monkey_business: &HashMap<Monkey, Job>) -> String {
+    let mut monkey_business = monkey_business.clone();
+    let root = Monkey::from("root");
+    let me = Monkey::from("humn");
+
+    monkey_business.remove(&me);
+
+    simplify(&mut monkey_business);
+
+    let root_job = monkey_business.get(&root).unwrap();
+    if let Job::Math(left_monkey, right_monkey, Operation::Add) = root_job {
+        format!(
+            "plug this into some solver, good luck :) \n{} == {}",
+            monkey_job_to_string(&monkey_business, left_monkey),
+            monkey_job_to_string(&monkey_business, right_monkey)
+        )
+    } else {
+        unreachable!()
+    }
+}
+
+pub struct Day21;
+
+impl aoc_common::Solution for Day21 {
+    const DAY: u8 = 21;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = HashMap<Monkey, Job>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
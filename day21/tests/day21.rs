@@ -0,0 +1,38 @@
+use aoc_common::{answer, Part};
+use day21::Day21;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+const HINT: &str = "plug this into some solver, good luck :) ";
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day21>(EXAMPLE, Part::One), "152");
+}
+
+/// Solving for `x` gives 301.
+#[test]
+fn part2_example() {
+    let equation = "((4 + (2 * (x - 3))) / 4) == 150";
+
+    assert_eq!(
+        answer::<Day21>(EXAMPLE, Part::Two),
+        format!("{HINT}\n{equation}")
+    );
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day21>(INPUT, Part::One), "38731621732448");
+}
+
+#[test]
+fn part2_input() {
+    let equation = "(2 * (18251426596821 - ((957 + (((2 * (821 + ((384 + ((((566 + (((((680 + ((2 * (((628 + ((((337 + ((217 + (((((((746 + ((206 + ((33 * (((824 + ((606 + (4 * (((471 + (9 * (((((45 + ((556 + (2 * ((((2 * (712 + (105 * (((647 + x) / 3) - 722)))) - 570) / 8) - 869))) / 2)) / 7) - 698) * 2) - 322))) / 7) - 382))) / 2)) / 7) - 138)) - 578)) / 3)) * 2) - 825) * 2) + 200) / 7) - 929)) / 2)) * 9) - 307) / 7)) * 6) + 634)) - 735)) + 313) / 2) - 436) / 3)) * 3) - 174) * 2)) / 5))) - 180) / 2)) / 2))) == 2228768553328";
+
+    assert_eq!(
+        answer::<Day21>(INPUT, Part::Two),
+        format!("{HINT}\n{equation}")
+    );
+}
@@ -0,0 +1,20 @@
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day22::Day22;
+
+const INPUT: &str = include_str!("../input");
+
+fn bench(c: &mut Criterion) {
+    let input = Day22::parse(INPUT).unwrap();
+
+    c.bench_function("day22 parse", |b| b.iter(|| Day22::parse(black_box(INPUT))));
+    c.bench_function("day22 part1", |b| {
+        b.iter(|| Day22::part1(black_box(&input)).map(|answer| answer.to_string()))
+    });
+    c.bench_function("day22 part2", |b| {
+        b.iter(|| Day22::part2(black_box(&input)).map(|answer| answer.to_string()))
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
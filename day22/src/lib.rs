@@ -0,0 +1,454 @@
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_possible_wrap
+)]
+
+use nom::{
+    branch::alt, character::complete::one_of, character::complete::u8, combinator::map,
+    multi::many0, IResult,
+};
+use std::fmt;
+
+use aoc_common::Grid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidTile,
+    MissingPath,
+    InvalidPath,
+    UnsupportedCube,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidTile => write!(f, "invalid tile, expected `.`, `#` or a space"),
+            ErrorKind::MissingPath => write!(f, "expected the map, a blank line and the path"),
+            ErrorKind::InvalidPath => write!(f, "expected a distance, `R` or `L`"),
+            ErrorKind::UnsupportedCube => write!(
+                f,
+                "part 2 only folds cubes laid out like the real input, with faces of 50 by 50 tiles"
+            ),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<(Board, Vec<Instruction>), ParseError> {
+    let groups = aoc_common::numbered_groups(input);
+    let [map, path] = &groups[..] else {
+        return Err(ParseError::end(ErrorKind::MissingPath, input));
+    };
+    let [(path_number, path)] = path[..] else {
+        return Err(ParseError::end(ErrorKind::MissingPath, input));
+    };
+
+    let mut rows: Vec<Vec<Option<Cell>>> = map.iter().map(|&(number, line)| {
+        line.chars().enumerate().map(|(i, c)| {
+            match c {
+                '.' => Ok(Some(Cell::Tile)),
+                '#' => Ok(Some(Cell::Wall)),
+                ' ' => Ok(None),
+                _ => Err(ParseError::new(ErrorKind::InvalidTile, number, i + 1, c)),
+            }
+        })
+        .collect()
+    })
+    .collect::<Result<_, _>>()?;
+
+    // Lines end after their last tile, so pad them to the widest one.
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, None);
+    }
+    let cells = Grid::from_rows(rows).expect("rows are padded to the same width");
+
+    let (rest, instructions) = parse_instructions(path).unwrap();
+    if !rest.is_empty() {
+        return Err(ParseError::at(ErrorKind::InvalidPath, path_number, path, rest));
+    }
+
+    Ok((Board { cells }, instructions))
+}
+
+fn turn(i: &str) -> IResult<&str, Rotation> {
+    let (matched, direction) = one_of("RL")(i)?;
+
+    let rotation = match direction {
+        'R' => Rotation::Clockwise,
+        'L' => Rotation::Counterclockwise,
+        _ => unreachable!(),
+    };
+
+    Ok((matched, rotation))
+}
+
+fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    many0(alt((map(turn, Instruction::Turn), map(u8, Instruction::Move))))(input)
+}
+
+#[derive(Clone, Copy)]
+enum Cell {
+    Wall,
+    Tile,
+}
+
+pub enum Rotation {
+    Clockwise,
+    Counterclockwise,
+}
+
+enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Direction {
+    fn turn(&self, rotation: &Rotation) -> Direction {
+        use Direction::{Down, Left, Right, Up};
+
+        match rotation {
+            Rotation::Clockwise => match self {
+                Right => Down,
+                Down => Left,
+                Left => Up,
+                Up => Right,
+            },
+            Rotation::Counterclockwise => match self {
+                Right => Up,
+                Down => Right,
+                Left => Down,
+                Up => Left,
+            },
+        }
+    }
+
+    fn to_digit(&self) -> usize {
+        match self {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        }
+    }
+
+    fn get_displacement(&self) -> (isize, isize) {
+        match self {
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Up => (0, -1),
+        }
+    }
+}
+
+pub type Distance = u8;
+
+pub enum Instruction {
+    Turn(Rotation),
+    Move(Distance),
+}
+
+type Coord = usize;
+type Pos = (Coord, Coord);
+
+/// The map, with `None` off the map.
+pub struct Board {
+    cells: Grid<Option<Cell>>,
+}
+
+impl Board {
+    fn get_start(&self) -> Pos {
+        self.cells
+            .iter()
+            .find(|(_, cell)| cell.is_some())
+            .map(|(pos, _)| pos)
+            .unwrap()
+    }
+
+    fn get_cell(&self, position: Pos, displacement: (isize, isize)) -> Option<Cell> {
+        self.cells.offset(position, displacement).and_then(|pos| self.cells[pos])
+    }
+
+    fn do_move1(&self, position: Pos, direction: &Direction, distance: Distance) -> Pos {
+        let displacement = direction.get_displacement();
+
+        let mut current_position = position;
+
+        for _ in 0..distance {
+            // Off the map, carry on from the opposite side of the board.
+            let mut next = self.cells.wrapping_offset(current_position, displacement);
+            while self.cells[next].is_none() {
+                next = self.cells.wrapping_offset(next, displacement);
+            }
+
+            match self.cells[next] {
+                Some(Cell::Wall) => return current_position,
+                Some(Cell::Tile) => current_position = next,
+                None => unreachable!(),
+            }
+        }
+
+        current_position
+    }
+
+    fn do_move2(&self, position: Pos, direction: &mut Direction, distance: Distance) -> Pos {
+        let mut displacement = direction.get_displacement();
+
+        let mut current_position = position;
+
+        for _ in 0..distance {
+            let (x, y) = current_position;
+            let (dx, dy) = displacement;
+            let (nx, ny) = (x as i32 + dx as i32, y as i32 + dy as i32);
+
+            if let Some(cell) = self.get_cell(current_position, displacement) {
+                match cell {
+                    Cell::Wall => return current_position,
+                    Cell::Tile => {
+                        current_position = (nx as usize, ny as usize);
+                    }
+                }
+            } else {
+                let face = get_face(current_position).expect("check_cube checks every face");
+                let new_direction = face.get_new_direction(direction);
+                let new_position = face.get_new_position((nx, ny));
+
+                match self.cells[new_position] {
+                    Some(cell) => match cell {
+                        Cell::Wall => return current_position,
+                        Cell::Tile => {
+                            *direction = new_direction;
+                            displacement = direction.get_displacement();
+                            current_position = new_position;
+                        }
+                    },
+                    None => unreachable!(),
+                }
+            }
+        }
+
+        current_position
+    }
+}
+
+pub fn part1((grid, instructions): &(Board, Vec<Instruction>)) -> usize {
+    let mut position = grid.get_start();
+    let mut direction = Direction::Right;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Turn(rotation) => direction = direction.turn(rotation),
+            Instruction::Move(distance) => {
+                position = grid.do_move1(position, &direction, *distance);
+            }
+        }
+    }
+
+    1000 * (position.1 + 1) + 4 * (position.0 + 1) + direction.to_digit()
+}
+
+enum Face {
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+}
+
+impl Face {
+    fn get_new_direction(&self, direction: &Direction) -> Direction {
+        match self {
+            Face::One | Face::Four => match direction {
+                Direction::Down => Direction::Down,
+                Direction::Left | Direction::Up | Direction::Right => Direction::Right,
+            },
+            Face::Two | Face::Five => match direction {
+                Direction::Up => Direction::Up,
+                Direction::Right | Direction::Down | Direction::Left => Direction::Left,
+            },
+            Face::Three | Face::Six => match direction {
+                Direction::Down | Direction::Left => Direction::Down,
+                Direction::Up | Direction::Right => Direction::Up,
+            },
+        }
+    }
+
+    fn get_new_position(&self, (x, y): (i32, i32)) -> Pos {
+        let new_pos = match self {
+            Face::One => {
+                if y < ONE_START_Y {
+                    (SIX_START_X, (x - ONE_START_X) + SIX_START_Y)
+                } else if x < ONE_START_X {
+                    (FOUR_START_X, FOUR_END_Y - (y - ONE_START_Y))
+                } else {
+                    unreachable!()
+                }
+            }
+            Face::Two => {
+                if y < TWO_START_Y {
+                    ((x - TWO_START_X) + SIX_START_X, SIX_END_Y)
+                } else if x > TWO_END_X {
+                    (FIVE_END_X, FIVE_END_Y - (y - TWO_START_Y))
+                } else if y > TWO_END_Y {
+                    (THREE_END_X, (x - TWO_START_X) + THREE_START_Y)
+                } else {
+                    unreachable!()
+                }
+            }
+            Face::Three => {
+                if x < THREE_START_X {
+                    ((y - THREE_START_Y) + FOUR_START_X, FOUR_START_Y)
+                } else if x > THREE_END_X {
+                    ((y - THREE_START_Y) + TWO_START_X, TWO_END_Y)
+                } else {
+                    unreachable!()
+                }
+            }
+            Face::Four => {
+                if x < FOUR_START_X {
+                    (ONE_START_X, ONE_END_Y - (y - FOUR_START_Y))
+                } else if y < FOUR_START_Y {
+                    (THREE_START_X, (x - FOUR_START_X) + THREE_START_Y)
+                } else {
+                    unreachable!()
+                }
+            }
+            Face::Five => {
+                if x > FIVE_END_X {
+                    (TWO_END_X, TWO_END_Y - (y - FIVE_START_Y))
+                } else if y > FIVE_END_Y {
+                    (SIX_END_X, (x - FIVE_START_X) + SIX_START_Y)
+                } else {
+                    unreachable!()
+                }
+            }
+            Face::Six => {
+                if x < SIX_START_X {
+                    ((y - SIX_START_Y) + ONE_START_X, ONE_START_Y)
+                } else if x > SIX_END_X {
+                    ((y - SIX_START_Y) + FIVE_START_X, FIVE_END_Y)
+                } else if y > SIX_END_Y {
+                    ((x - SIX_START_X) + TWO_START_X, TWO_START_Y)
+                } else {
+                    unreachable!()
+                }
+            }
+        };
+        (new_pos.0 as usize, new_pos.1 as usize)
+    }
+}
+
+const ONE_START_X: i32 = 50;
+const ONE_END_X: i32 = 99;
+const ONE_START_Y: i32 = 0;
+const ONE_END_Y: i32 = 49;
+
+const TWO_START_X: i32 = 100;
+const TWO_END_X: i32 = 149;
+const TWO_START_Y: i32 = 0;
+const TWO_END_Y: i32 = 49;
+
+const THREE_START_X: i32 = 50;
+const THREE_END_X: i32 = 99;
+const THREE_START_Y: i32 = 50;
+const THREE_END_Y: i32 = 99;
+
+const FOUR_START_X: i32 = 0;
+const FOUR_END_X: i32 = 49;
+const FOUR_START_Y: i32 = 100;
+const FOUR_END_Y: i32 = 149;
+
+const FIVE_START_X: i32 = 50;
+const FIVE_END_X: i32 = 99;
+const FIVE_START_Y: i32 = 100;
+const FIVE_END_Y: i32 = 149;
+
+const SIX_START_X: i32 = 0;
+const SIX_END_X: i32 = 49;
+const SIX_START_Y: i32 = 150;
+const SIX_END_Y: i32 = 199;
+
+fn get_face((x, y): Pos) -> Option<Face> {
+    let (x, y) = (x as i32, y as i32);
+
+    if (ONE_START_X..=ONE_END_X).contains(&x) && (ONE_START_Y..=ONE_END_Y).contains(&y) {
+        Some(Face::One)
+    } else if (TWO_START_X..=TWO_END_X).contains(&x) && (TWO_START_Y..=TWO_END_Y).contains(&y) {
+        Some(Face::Two)
+    } else if (THREE_START_X..=THREE_END_X).contains(&x) && (THREE_START_Y..=THREE_END_Y).contains(&y) {
+        Some(Face::Three)
+    } else if (FOUR_START_X..=FOUR_END_X).contains(&x) && (FOUR_START_Y..=FOUR_END_Y).contains(&y) {
+        Some(Face::Four)
+    } else if (FIVE_START_X..=FIVE_END_X).contains(&x) && (FIVE_START_Y..=FIVE_END_Y).contains(&y) {
+        Some(Face::Five)
+    } else if (SIX_START_X..=SIX_END_X).contains(&x) && (SIX_START_Y..=SIX_END_Y).contains(&y) {
+        Some(Face::Six)
+    } else {
+        None
+    }
+}
+
+/// Checks that the map is the one cube layout part 2 knows how to fold,
+/// pointing at the first position that is on the map but not on a face, or
+/// the other way around.
+pub fn check_cube(board: &Board) -> Result<(), ParseError> {
+    let width = board.cells.width().max(SIX_END_X as usize + 1);
+    let height = board.cells.height().max(SIX_END_Y as usize + 1);
+
+    for y in 0..height {
+        for x in 0..width {
+            let on_map = board.cells.get((x, y)).is_some_and(Option::is_some);
+            if on_map != get_face((x, y)).is_some() {
+                return Err(ParseError::new(ErrorKind::UnsupportedCube, y + 1, x + 1, ""));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn part2((grid, instructions): &(Board, Vec<Instruction>)) -> Result<usize, ParseError> {
+    check_cube(grid)?;
+
+    let mut position = grid.get_start();
+    let mut direction = Direction::Right;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Turn(rotation) => direction = direction.turn(rotation),
+            Instruction::Move(distance) => {
+                position = grid.do_move2(position, &mut direction, *distance);
+            }
+        }
+    }
+
+    Ok(1000 * (position.1 + 1) + 4 * (position.0 + 1) + direction.to_digit())
+}
+
+pub struct Day22;
+
+impl aoc_common::Solution for Day22 {
+    const DAY: u8 = 22;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = (Board, Vec<Instruction>);
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        part2(input)
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_common::{answer, Part};
+use day22::{Day22, ErrorKind};
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day22>(EXAMPLE, Part::One), "6032");
+}
+
+#[test]
+fn part2_example() {
+    // Part 2 only folds cubes laid out like the real input, which the
+    // example's isn't.
+    let input = day22::parse(EXAMPLE).unwrap();
+    let err = day22::part2(&input).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnsupportedCube);
+    assert_eq!((err.line, err.column), (1, 9));
+    assert!(aoc_common::solve::<Day22>(EXAMPLE, &[Part::Two], false, &mut |_| {}).is_err());
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day22>(INPUT, Part::One), "186128");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day22>(INPUT, Part::Two), "34426");
+}
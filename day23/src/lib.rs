@@ -0,0 +1,218 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+};
+
+use aoc_common::{Grid, Offset, Pos, NEIGHBOURS8};
+
+/// Whether there is an elf on each tile.
+pub type Elves = Grid<bool>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidTile,
+    UnevenRow,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidTile => write!(f, "invalid tile, expected `#` or `.`"),
+            ErrorKind::UnevenRow => write!(f, "row is not as wide as the first one"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<Elves, ParseError> {
+    Grid::parse(input, ErrorKind::InvalidTile, ErrorKind::UnevenRow, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+#[derive(Debug)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+fn count_free(elves: &Elves, pos: Pos, displacements: &[Offset]) -> usize {
+    displacements.iter().filter(|&&offset| !elves.offset(pos, offset).is_some_and(|pos| elves[pos])).count()
+}
+
+fn count_free_north(elves: &Elves, pos: Pos) -> usize {
+    count_free(elves, pos, &[(-1, -1), (0, -1), (1, -1)])
+}
+
+fn count_free_south(elves: &Elves, pos: Pos) -> usize {
+    count_free(elves, pos, &[(-1, 1), (0, 1), (1, 1)])
+}
+
+fn count_free_west(elves: &Elves, pos: Pos) -> usize {
+    count_free(elves, pos, &[(-1, -1), (-1, 0), (-1, 1)])
+}
+
+fn count_free_east(elves: &Elves, pos: Pos) -> usize {
+    count_free(elves, pos, &[(1, -1), (1, 0), (1, 1)])
+}
+
+fn count_free_neightbours(elves: &Elves, pos: Pos) -> usize {
+    count_free(elves, pos, &NEIGHBOURS8)
+}
+
+/// Positions of all elves.
+fn positions(elves: &Elves) -> Vec<Pos> {
+    elves.iter().filter(|(_, &elf)| elf).map(|(pos, _)| pos).collect()
+}
+
+/// Whether an elf is on the edge of the grid, where it couldn't move outwards.
+fn touches_edge(elves: &Elves) -> bool {
+    positions(elves).iter().any(|&(x, y)| {
+        x == 0 || y == 0 || x == elves.width() - 1 || y == elves.height() - 1
+    })
+}
+
+type Rounds = usize;
+
+fn elf_dance(elves: &Elves, rounds: Option<Rounds>) -> (Elves, Rounds) {
+    let mut elves_copy = elves.clone();
+
+    let mut directions_considered = VecDeque::from([
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ]);
+
+    let mut proposed: HashMap<Pos, Vec<Pos>> = HashMap::new();
+    let mut rounds_done = 0;
+    loop {
+        rounds_done += 1;
+
+        if touches_edge(&elves_copy) {
+            elves_copy = elves_copy.expand(10, false);
+        }
+
+        for elf in &positions(&elves_copy) {
+            if count_free_neightbours(&elves_copy, *elf) != 8 {
+                for direction in &directions_considered {
+                    let considered_pos = match direction {
+                        Direction::North => {
+                            if count_free_north(&elves_copy, *elf) == 3 {
+                                Some((elf.0, elf.1 - 1))
+                            } else {
+                                None
+                            }
+                        }
+                        Direction::East => {
+                            if count_free_east(&elves_copy, *elf) == 3 {
+                                Some((elf.0 + 1, elf.1))
+                            } else {
+                                None
+                            }
+                        }
+                        Direction::South => {
+                            if count_free_south(&elves_copy, *elf) == 3 {
+                                Some((elf.0, elf.1 + 1))
+                            } else {
+                                None
+                            }
+                        }
+                        Direction::West => {
+                            if count_free_west(&elves_copy, *elf) == 3 {
+                                Some((elf.0 - 1, elf.1))
+                            } else {
+                                None
+                            }
+                        }
+                    };
+
+                    if let Some(considered_pos) = considered_pos {
+                        let entry = proposed.entry(considered_pos).or_default();
+                        entry.push(*elf);
+                        break;
+                    }
+                }
+            }
+        }
+
+
+        let mut any_moves = false;
+        for (pos, elves_proposed) in &proposed {
+            if elves_proposed.len() == 1 {
+                elves_copy[elves_proposed[0]] = false;
+                elves_copy[*pos] = true;
+                any_moves = true;
+            }
+        }
+
+        if let Some(rounds) = rounds {
+            if rounds_done == rounds {
+                break;
+            }
+        } else if !any_moves {
+            break;
+        }
+
+        directions_considered.rotate_left(1);
+        proposed.clear();
+    }
+
+    (elves_copy, rounds_done)
+}
+
+fn get_boundaries(elves: &Elves) -> (usize, usize, usize, usize) {
+    positions(elves).iter().fold(
+        (usize::MAX, usize::MIN, usize::MAX, usize::MIN),
+        |(min_x, max_x, min_y, max_y), (x, y)| {
+            (min_x.min(*x), max_x.max(*x), min_y.min(*y), max_y.max(*y))
+        },
+    )
+}
+
+pub fn part1(elves: &Elves) -> usize {
+    let (elves_copy, _) = elf_dance(elves, Some(10));
+
+    let (min_x, max_x, min_y, max_y) = get_boundaries(&elves_copy);
+
+    (min_x..=max_x).fold(0, |acc, x| {
+        (min_y..=max_y).fold(0, |acc, y| {
+            if elves_copy[(x, y)] {
+                acc
+            } else {
+                acc + 1
+            }
+        }) + acc
+    })
+}
+
+pub fn part2(elves: &Elves) -> usize {
+    elf_dance(elves, None).1
+}
+
+pub struct Day23;
+
+impl aoc_common::Solution for Day23 {
+    const DAY: u8 = 23;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Elves;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
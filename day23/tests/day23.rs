@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day23::Day23;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day23>(EXAMPLE, Part::One), "110");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day23>(EXAMPLE, Part::Two), "20");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day23>(INPUT, Part::One), "4138");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day23>(INPUT, Part::Two), "1010");
+}
@@ -0,0 +1,233 @@
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_possible_wrap
+)]
+
+use std::fmt;
+
+use aoc_common::{Grid, Search};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingValley,
+    InvalidCell,
+    UnevenRow,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingValley => write!(f, "expected a valley surrounded by walls"),
+            ErrorKind::InvalidCell => write!(f, "invalid cell, expected `.`, `^`, `>`, `v` or `<`"),
+            ErrorKind::UnevenRow => write!(f, "row is not as wide as the first one"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(input: &str) -> Result<Valley, ParseError> {
+    let lines: Vec<(usize, &str)> = aoc_common::numbered_lines(input).collect();
+
+    if lines.len() < 3 {
+        return Err(ParseError::end(ErrorKind::MissingValley, input));
+    }
+
+    let fields: Vec<Vec<Cell>> = lines
+        .iter()
+        .skip(1)
+        .take(lines.len() - 2)
+        .map(|&(number, line)| {
+            line.chars()
+                .enumerate()
+                .skip(1)
+                .take_while(|(_, c)| *c != '#')
+                .map(move |(i, c)| match c {
+                    '^' => Ok(Cell(1, 0, 0, 0)),
+                    '>' => Ok(Cell(0, 1, 0, 0)),
+                    'v' => Ok(Cell(0, 0, 1, 0)),
+                    '<' => Ok(Cell(0, 0, 0, 1)),
+                    '.' => Ok(Cell::default()),
+                    _ => Err(ParseError::new(ErrorKind::InvalidCell, number, i + 1, c)),
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    let fields = Grid::from_rows(fields).map_err(|row| {
+        let (number, line) = lines[row + 1];
+
+        ParseError::line(ErrorKind::UnevenRow, number, line)
+    })?;
+
+    Ok(Valley { fields })
+}
+
+pub type Coord = i32;
+/// Position inside the walls, with the entrance at `(0, -1)`.
+pub type Pos = (Coord, Coord);
+
+#[derive(Default, Clone)]
+struct Cell(usize, usize, usize, usize);
+
+impl Cell {
+    fn desctructure(&self) -> (usize, usize, usize, usize) {
+        (self.0, self.1, self.2, self.3)
+    }
+
+    fn add(&mut self, other: &Cell) {
+        self.0 += other.0;
+        self.1 += other.1;
+        self.2 += other.2;
+        self.3 += other.3;
+    }
+
+    fn sum(&self) -> usize {
+        self.0 + self.1 + self.2 + self.3
+    }
+}
+
+#[derive(Clone)]
+pub struct Valley {
+    fields: Grid<Cell>,
+}
+
+impl Valley {
+    pub fn width(&self) -> usize {
+        self.fields.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.fields.height()
+    }
+
+    pub fn entrance(&self) -> Pos {
+        (0, -1)
+    }
+
+    pub fn exit(&self) -> Pos {
+        (self.width() as i32 - 1, self.height() as i32)
+    }
+
+    fn updated_fields(&self) -> Grid<Cell> {
+        let fields = &self.fields;
+        let mut new = Grid::new(fields.width(), fields.height(), Cell::default());
+
+        // Blizzards leaving the valley come back in on the opposite side.
+        for (y, row) in fields.rows().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let (up, right, down, left) = cell.desctructure();
+
+                new[fields.wrapping_offset((x, y), (0, -1))].add(&Cell(up, 0, 0, 0));
+                new[fields.wrapping_offset((x, y), (1, 0))].add(&Cell(0, right, 0, 0));
+                new[fields.wrapping_offset((x, y), (0, 1))].add(&Cell(0, 0, down, 0));
+                new[fields.wrapping_offset((x, y), (-1, 0))].add(&Cell(0, 0, 0, left));
+            }
+        }
+
+        new
+    }
+
+    /// Moves every blizzard one step.
+    pub fn update(&mut self) {
+        self.fields = self.updated_fields();
+    }
+}
+
+type Time = usize;
+
+/// Which positions are free of blizzards at each minute, worked out as far
+/// as a search needs them.
+struct Forecast {
+    valley: Valley,
+    free: Vec<Grid<bool>>,
+}
+
+impl Forecast {
+    fn new(valley: &Valley) -> Forecast {
+        Forecast {
+            valley: valley.clone(),
+            free: vec![],
+        }
+    }
+
+    fn is_free(&mut self, pos @ (x, y): Pos, time: Time) -> bool {
+        if pos == self.valley.entrance() || pos == self.valley.exit() {
+            return true;
+        }
+
+        while self.free.len() <= time {
+            self.free.push(self.valley.fields.map(|cell| cell.sum() == 0));
+            self.valley.update();
+        }
+
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) => self.free[time].get((x, y)).copied().unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// Positions that are free of blizzards after the minute following
+    /// `time`, including staying put.
+    fn possible_moves(&mut self, (x, y): Pos, time: Time) -> Vec<Pos> {
+        let displacements = [(0, 1), (0, -1), (1, 0), (-1, 0), (0, 0)];
+
+        displacements
+            .iter()
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .filter(|&new| self.is_free(new, time + 1))
+            .collect()
+    }
+
+    /// Time of arriving at `target` when leaving `start` at `time`.
+    fn travel(&mut self, start: Pos, target: Pos, time: Time) -> Time {
+        Search::new((start, time))
+            .bfs(
+                |&(pos, time)| {
+                    self.possible_moves(pos, time)
+                        .into_iter()
+                        .map(move |new| (new, time + 1))
+                },
+                |&(pos, _)| pos == target,
+            )
+            .unwrap()
+            .node
+            .1
+    }
+}
+
+pub fn part1(valley: &Valley) -> usize {
+    Forecast::new(valley).travel(valley.entrance(), valley.exit(), 0)
+}
+
+pub fn part2(valley: &Valley) -> usize {
+    let (start, end) = (valley.entrance(), valley.exit());
+    let mut forecast = Forecast::new(valley);
+
+    let time = forecast.travel(start, end, 0);
+    let time = forecast.travel(end, start, time);
+    forecast.travel(start, end, time)
+}
+
+pub struct Day24;
+
+impl aoc_common::Solution for Day24 {
+    const DAY: u8 = 24;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Valley;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part2(input))
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::{answer, Part};
+use day24::Day24;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day24>(EXAMPLE, Part::One), "18");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(answer::<Day24>(EXAMPLE, Part::Two), "54");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day24>(INPUT, Part::One), "245");
+}
+
+#[test]
+fn part2_input() {
+    assert_eq!(answer::<Day24>(INPUT, Part::Two), "798");
+}
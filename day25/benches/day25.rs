@@ -0,0 +1,17 @@
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day25::Day25;
+
+const INPUT: &str = include_str!("../input");
+
+fn bench(c: &mut Criterion) {
+    let input = Day25::parse(INPUT).unwrap();
+
+    c.bench_function("day25 parse", |b| b.iter(|| Day25::parse(black_box(INPUT))));
+    c.bench_function("day25 part1", |b| {
+        b.iter(|| Day25::part1(black_box(&input)).map(|answer| answer.to_string()))
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
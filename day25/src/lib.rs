@@ -0,0 +1,114 @@
+use std::fmt;
+
+type Snafu = char;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidDigit,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidDigit => write!(f, "invalid SNAFU digit, expected `2`, `1`, `0`, `-` or `=`"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// The SNAFU numbers, one per line.
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    aoc_common::numbered_lines(input)
+        .map(|(number, line)| match line.chars().position(|c| !"210-=".contains(c)) {
+            Some(i) => {
+                let c = line.chars().nth(i).unwrap();
+
+                Err(ParseError::new(ErrorKind::InvalidDigit, number, i + 1, c))
+            }
+            None => Ok(line.to_owned()),
+        })
+        .collect()
+}
+
+fn snafu_to_decimal(c: Snafu) -> f64 {
+    match c {
+        '2' => 2.0,
+        '1' => 1.0,
+        '0' => 0.0,
+        '-' => -1.0,
+        '=' => -2.0,
+        _ => unreachable!()
+    }
+}
+
+fn decimal_to_snafu(n: i64) -> Snafu {
+    match n {
+        2 => '2',
+        1 => '1',
+        0 => '0',
+        -1 => '-',
+        -2 => '=',
+        _ => unreachable!()
+    }
+}
+
+fn pow5(x: f64) -> f64 {
+    5f64.powf(x)
+}
+
+pub fn to_decimal(snafu: &str) -> i64 {
+    snafu.chars().rev().enumerate().fold(0, |acc, (i, c)| {
+        acc + (snafu_to_decimal(c) * pow5(i as f64)) as i64
+    })
+}
+
+fn to_decimals(lines: &[String]) -> Vec<i64> {
+    lines.iter().map(|line| to_decimal(line)).collect()
+}
+
+pub fn to_snafu(mut number: i64) -> String {
+    let mut digits = vec![];
+    loop {
+        digits.push(decimal_to_snafu(((number % 5) + 2) % 5 - 2));
+        number += 2;
+        number /= 5;
+
+        if number == 0 {
+            break;
+        }
+   }
+
+    digits.into_iter().rev().collect()
+}
+
+pub fn part1(lines: &[String]) -> String {
+    let sum = to_decimals(lines).iter().sum();
+    let snafu = to_snafu(sum);
+
+    assert_eq!(to_decimal(&snafu), sum);
+
+    snafu
+}
+
+pub struct Day25;
+
+impl aoc_common::Solution for Day25 {
+    const DAY: u8 = 25;
+    const INPUT: &'static str = aoc_common::input_path!();
+
+    type Input = Vec<String>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<impl std::fmt::Display, Self::Error> {
+        Ok(aoc_common::NoAnswer)
+    }
+}
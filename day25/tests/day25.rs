@@ -0,0 +1,15 @@
+use aoc_common::{answer, Part};
+use day25::Day25;
+
+const EXAMPLE: &str = include_str!("../input_test");
+const INPUT: &str = include_str!("../input");
+
+#[test]
+fn part1_example() {
+    assert_eq!(answer::<Day25>(EXAMPLE, Part::One), "2=-1=0");
+}
+
+#[test]
+fn part1_input() {
+    assert_eq!(answer::<Day25>(INPUT, Part::One), "2011-=2=-1020-1===-1");
+}